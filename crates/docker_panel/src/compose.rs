@@ -0,0 +1,91 @@
+use crate::docker;
+use anyhow::Result;
+use collections::HashMap;
+use std::path::PathBuf;
+
+/// The labels Docker Compose puts on the containers it creates.
+const PROJECT_LABEL: &str = "com.docker.compose.project";
+const CONFIG_FILES_LABEL: &str = "com.docker.compose.project.config_files";
+const WORKING_DIR_LABEL: &str = "com.docker.compose.project.working_dir";
+
+/// The Docker Compose project a container belongs to, as recorded in its labels.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComposeProject {
+    pub name: String,
+    pub config_files: Vec<PathBuf>,
+    pub working_dir: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComposeCommand {
+    Up,
+    Down,
+}
+
+impl ComposeProject {
+    pub fn from_labels(labels: &HashMap<String, String>) -> Option<Self> {
+        let name = labels.get(PROJECT_LABEL)?.clone();
+        let config_files = labels
+            .get(CONFIG_FILES_LABEL)
+            .map(|files| {
+                files
+                    .split(',')
+                    .filter(|file| !file.is_empty())
+                    .map(PathBuf::from)
+                    .collect()
+            })
+            .unwrap_or_default();
+        let working_dir = labels.get(WORKING_DIR_LABEL).map(PathBuf::from);
+        Some(Self {
+            name,
+            config_files,
+            working_dir,
+        })
+    }
+
+    /// Runs `docker compose up -d` or `docker compose down` for the project.
+    pub async fn run(&self, docker_host: &str, command: ComposeCommand) -> Result<()> {
+        let mut args = vec!["compose".into(), "--project-name".into(), self.name.clone()];
+        for config_file in &self.config_files {
+            args.push("--file".into());
+            args.push(config_file.to_string_lossy().into_owned());
+        }
+        match command {
+            ComposeCommand::Up => args.extend(["up".into(), "--detach".into()]),
+            ComposeCommand::Down => args.push("down".into()),
+        }
+
+        docker::run_cli(docker_host, &args, self.working_dir.as_deref()).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_labels() {
+        let labels = HashMap::from_iter([
+            (PROJECT_LABEL.to_string(), "web".to_string()),
+            (
+                CONFIG_FILES_LABEL.to_string(),
+                "/src/web/compose.yaml,/src/web/compose.override.yaml".to_string(),
+            ),
+            (WORKING_DIR_LABEL.to_string(), "/src/web".to_string()),
+        ]);
+        assert_eq!(
+            ComposeProject::from_labels(&labels),
+            Some(ComposeProject {
+                name: "web".into(),
+                config_files: vec![
+                    "/src/web/compose.yaml".into(),
+                    "/src/web/compose.override.yaml".into()
+                ],
+                working_dir: Some("/src/web".into()),
+            })
+        );
+
+        assert_eq!(ComposeProject::from_labels(&HashMap::default()), None);
+    }
+}
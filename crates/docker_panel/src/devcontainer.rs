@@ -0,0 +1,823 @@
+use crate::docker::{
+    self, ContainerState, ContainerSummary, CreateContainerRequest, DockerClient, HostConfig,
+    MountSpec, PortBinding,
+};
+use anyhow::{anyhow, Context, Result};
+use collections::HashMap;
+use futures::channel::mpsc;
+use project::Fs;
+use serde::Deserialize;
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// Where a worktree may keep its dev container configuration, in order of precedence.
+const CONFIG_PATHS: [&str; 2] = [".devcontainer/devcontainer.json", ".devcontainer.json"];
+
+/// The labels the Dev Containers CLI puts on the containers it creates, so
+/// containers created by either tool are recognized by the other.
+pub const LOCAL_FOLDER_LABEL: &str = "devcontainer.local_folder";
+pub const CONFIG_FILE_LABEL: &str = "devcontainer.config_file";
+
+/// Keeps the container running after it starts, regardless of its image's command.
+const KEEP_ALIVE_COMMAND: [&str; 3] = ["/bin/sh", "-c", "while sleep 1000; do :; done"];
+
+/// The subset of `devcontainer.json` the panel understands.
+///
+/// See <https://containers.dev/implementors/json_reference/>.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DevContainerConfig {
+    pub name: Option<String>,
+    pub image: Option<String>,
+    /// Relative to the directory containing `devcontainer.json`.
+    pub docker_file: Option<String>,
+    /// The build context for `docker_file`, relative to the directory containing
+    /// `devcontainer.json`.
+    pub context: Option<String>,
+    /// Supersedes `docker_file` and `context`.
+    pub build: Option<BuildConfig>,
+    #[serde(default)]
+    pub forward_ports: Vec<ForwardPort>,
+    /// Ports published when the container is created, which predates `forwardPorts`.
+    pub app_port: Option<AppPort>,
+    /// Run once the container has been created.
+    pub post_create_command: Option<LifecycleCommand>,
+    /// Run each time the container starts, after `post_create_command`.
+    pub post_start_command: Option<LifecycleCommand>,
+    /// Where the workspace is mounted in the container.
+    pub workspace_folder: Option<String>,
+    /// Mounted in addition to the workspace.
+    #[serde(default)]
+    pub mounts: Vec<MountConfig>,
+}
+
+/// The `build` section of `devcontainer.json`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildConfig {
+    /// Relative to the directory containing `devcontainer.json`.
+    pub dockerfile: Option<String>,
+    /// Relative to the directory containing `devcontainer.json`.
+    pub context: Option<String>,
+    #[serde(default)]
+    pub args: HashMap<String, String>,
+}
+
+/// An entry of `forwardPorts`, either a port on the container or a `host:port` pair.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum ForwardPort {
+    Port(u16),
+    Address(String),
+}
+
+impl ForwardPort {
+    /// The forwarded port, unless it's a port of another host, such as another
+    /// service of a Compose project, which can't be reached from the host.
+    pub fn container_port(&self) -> Option<u16> {
+        match self {
+            ForwardPort::Port(port) => Some(*port),
+            ForwardPort::Address(address) => {
+                let (host, port) = address.rsplit_once(':')?;
+                if matches!(host, "localhost" | "127.0.0.1") {
+                    port.parse().ok()
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+/// `appPort`, one or more ports given as a number or a `host:container` string.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum AppPort {
+    One(AppPortEntry),
+    Many(Vec<AppPortEntry>),
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum AppPortEntry {
+    Port(u16),
+    Mapping(String),
+}
+
+impl AppPortEntry {
+    /// The host and container ports, which are the same when only one is given.
+    fn ports(&self) -> Option<(u16, u16)> {
+        match self {
+            AppPortEntry::Port(port) => Some((*port, *port)),
+            AppPortEntry::Mapping(mapping) => match mapping.split_once(':') {
+                Some((host, container)) => Some((host.parse().ok()?, container.parse().ok()?)),
+                None => {
+                    let port = mapping.parse().ok()?;
+                    Some((port, port))
+                }
+            },
+        }
+    }
+}
+
+/// An entry of `mounts`, either a `docker run --mount` style string, e.g.
+/// `source=cache,target=/cache,type=volume`, or the equivalent object.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum MountConfig {
+    Spec(String),
+    Object {
+        source: Option<String>,
+        target: String,
+        #[serde(rename = "type")]
+        kind: String,
+    },
+}
+
+impl MountConfig {
+    /// The mount to create, with `substitute` applied to its source and target,
+    /// or `None` if it has no target.
+    fn to_mount_spec(&self, substitute: impl Fn(&str) -> String) -> Option<MountSpec> {
+        match self {
+            MountConfig::Spec(spec) => {
+                let mut mount = MountSpec {
+                    target: String::new(),
+                    source: String::new(),
+                    // Like `docker run --mount`, mounts are volumes unless specified.
+                    kind: "volume".into(),
+                    read_only: false,
+                };
+                for option in spec.split(',') {
+                    let (key, value) = option.split_once('=').unwrap_or((option, "true"));
+                    match key.trim() {
+                        "type" => mount.kind = value.trim().into(),
+                        "source" | "src" => mount.source = substitute(value.trim()),
+                        "target" | "destination" | "dst" => mount.target = substitute(value.trim()),
+                        "readonly" | "ro" => {
+                            mount.read_only = !matches!(value.trim(), "false" | "0")
+                        }
+                        _ => {}
+                    }
+                }
+                (!mount.target.is_empty()).then_some(mount)
+            }
+            MountConfig::Object {
+                source,
+                target,
+                kind,
+            } => Some(MountSpec {
+                target: substitute(target),
+                source: source.as_deref().map(&substitute).unwrap_or_default(),
+                kind: kind.clone(),
+                read_only: false,
+            }),
+        }
+    }
+}
+
+/// Replaces `${localEnv:NAME}` and `${localEnv:NAME:default}` with the value of
+/// the environment variable, or the default if it's unset.
+fn substitute_local_env(value: &str) -> String {
+    const PREFIX: &str = "${localEnv:";
+    let mut result = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find(PREFIX) {
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        let variable = &rest[start + PREFIX.len()..start + len];
+        let (name, default) = variable.split_once(':').unwrap_or((variable, ""));
+        result.push_str(&rest[..start]);
+        result.push_str(&std::env::var(name).unwrap_or_else(|_| default.to_string()));
+        rest = &rest[start + len + 1..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// A command run in the container at a point in its lifecycle, either a string
+/// run by a shell or an array run without one.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum LifecycleCommand {
+    Shell(String),
+    Args(Vec<String>),
+}
+
+impl LifecycleCommand {
+    pub fn argv(&self) -> Vec<String> {
+        match self {
+            LifecycleCommand::Shell(command) => {
+                vec!["/bin/sh".into(), "-c".into(), command.clone()]
+            }
+            LifecycleCommand::Args(args) => args.clone(),
+        }
+    }
+}
+
+impl fmt::Display for LifecycleCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LifecycleCommand::Shell(command) => write!(f, "{command}"),
+            LifecycleCommand::Args(args) => write!(f, "{}", args.join(" ")),
+        }
+    }
+}
+
+impl fmt::Display for ForwardPort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ForwardPort::Port(port) => write!(f, "{port}"),
+            ForwardPort::Address(address) => write!(f, "{address}"),
+        }
+    }
+}
+
+impl DevContainerConfig {
+    /// Parses `devcontainer.json`, which allows comments and trailing commas.
+    pub fn parse(content: &str) -> Result<Self> {
+        Ok(serde_json_lenient::from_str(content)?)
+    }
+
+    /// The lifecycle commands to run, in order, once a container has started,
+    /// including `postCreateCommand` if the container was just created.
+    pub fn lifecycle_commands(&self, created: bool) -> Vec<(&'static str, LifecycleCommand)> {
+        let post_create = self
+            .post_create_command
+            .clone()
+            .filter(|_| created)
+            .map(|command| ("postCreateCommand", command));
+        let post_start = self
+            .post_start_command
+            .clone()
+            .map(|command| ("postStartCommand", command));
+        post_create.into_iter().chain(post_start).collect()
+    }
+
+    pub fn dockerfile(&self) -> Option<&str> {
+        self.build
+            .as_ref()
+            .and_then(|build| build.dockerfile.as_deref())
+            .or(self.docker_file.as_deref())
+    }
+
+    pub fn build_context(&self) -> &str {
+        self.build
+            .as_ref()
+            .and_then(|build| build.context.as_deref())
+            .or(self.context.as_deref())
+            .unwrap_or(".")
+    }
+
+    /// The host and container ports of each `appPort` entry.
+    pub fn app_ports(&self) -> Vec<(u16, u16)> {
+        let entries = match &self.app_port {
+            Some(AppPort::One(entry)) => std::slice::from_ref(entry),
+            Some(AppPort::Many(entries)) => entries.as_slice(),
+            None => &[],
+        };
+        entries.iter().filter_map(AppPortEntry::ports).collect()
+    }
+
+    /// The container ports to make available on the host, from both
+    /// `forwardPorts` and `appPort`, without duplicates.
+    pub fn ports_to_forward(&self) -> Vec<u16> {
+        let mut ports = Vec::new();
+        let forward_ports = self
+            .forward_ports
+            .iter()
+            .filter_map(ForwardPort::container_port);
+        let app_ports = self.app_ports().into_iter().map(|(_, container)| container);
+        for port in forward_ports.chain(app_ports) {
+            if !ports.contains(&port) {
+                ports.push(port);
+            }
+        }
+        ports
+    }
+
+    /// `build.args` as `NAME=value` pairs, sorted so builds are reproducible.
+    pub fn build_args(&self) -> Vec<String> {
+        let mut build_args = self
+            .build
+            .iter()
+            .flat_map(|build| &build.args)
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>();
+        build_args.sort();
+        build_args
+    }
+}
+
+/// The workspace's container, once [`DevContainer::run`] has it running.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RunningContainer {
+    /// The container was already running.
+    Existing(String),
+    /// The container existed but was stopped or paused, and has been resumed.
+    Started(String),
+    /// The container has been created from the configured image, and started.
+    Created(String),
+}
+
+impl RunningContainer {
+    pub fn id(&self) -> &str {
+        match self {
+            RunningContainer::Existing(id)
+            | RunningContainer::Started(id)
+            | RunningContainer::Created(id) => id,
+        }
+    }
+}
+
+/// A dev container configuration found in one of the workspace's worktrees.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DevContainer {
+    /// The worktree root the configuration applies to.
+    pub local_folder: PathBuf,
+    pub config_path: PathBuf,
+    pub config: DevContainerConfig,
+}
+
+impl DevContainer {
+    pub fn name(&self) -> String {
+        self.config
+            .name
+            .clone()
+            .unwrap_or_else(|| self.local_folder_name())
+    }
+
+    fn local_folder_name(&self) -> String {
+        self.local_folder
+            .file_name()
+            .map_or_else(String::new, |name| name.to_string_lossy().into_owned())
+    }
+
+    /// Replaces the variables in a `devcontainer.json` value that refer to the
+    /// host, such as `${localWorkspaceFolder}`.
+    fn substitute_local_variables(&self, value: &str) -> String {
+        substitute_local_env(value)
+            .replace(
+                "${localWorkspaceFolder}",
+                &self.local_folder.to_string_lossy(),
+            )
+            .replace("${localWorkspaceFolderBasename}", &self.local_folder_name())
+    }
+
+    /// Where the workspace is mounted in the container, `/workspaces/<folder name>`
+    /// unless configured.
+    pub fn workspace_folder(&self) -> String {
+        match &self.config.workspace_folder {
+            Some(folder) => self.substitute_local_variables(folder),
+            None => format!("/workspaces/{}", self.local_folder_name()),
+        }
+    }
+
+    /// The configured mounts, preceded by a bind mount of the workspace unless
+    /// one of them is already mounted at the workspace folder.
+    pub fn mounts(&self) -> Vec<MountSpec> {
+        let workspace_folder = self.workspace_folder();
+        let mut mounts = self
+            .config
+            .mounts
+            .iter()
+            .filter_map(|mount| {
+                mount.to_mount_spec(|value| {
+                    self.substitute_local_variables(value)
+                        .replace("${containerWorkspaceFolder}", &workspace_folder)
+                })
+            })
+            .collect::<Vec<_>>();
+        if !mounts.iter().any(|mount| mount.target == workspace_folder) {
+            mounts.insert(
+                0,
+                MountSpec {
+                    target: workspace_folder,
+                    source: self.local_folder.to_string_lossy().into_owned(),
+                    kind: "bind".into(),
+                    read_only: false,
+                },
+            );
+        }
+        mounts
+    }
+
+    /// The tag of the images built for the dev container, so rebuilding replaces
+    /// the previous image.
+    fn image_tag(&self) -> String {
+        let name = self
+            .name()
+            .to_lowercase()
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-') {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect::<String>();
+        format!("zed-devcontainer-{}", name.trim_matches(['_', '.', '-']))
+    }
+
+    fn config_dir(&self) -> Result<&Path> {
+        self.config_path
+            .parent()
+            .context("dev container configuration has no parent directory")
+    }
+
+    fn missing_image_error(&self) -> anyhow::Error {
+        anyhow!(
+            "{} specifies neither an image nor a Dockerfile",
+            self.config_path.display()
+        )
+    }
+
+    /// Whether the container was created for this dev container, by the panel
+    /// or the Dev Containers CLI.
+    fn owns(&self, container: &ContainerSummary) -> bool {
+        container.labels.get(LOCAL_FOLDER_LABEL).map(String::as_str)
+            == Some(self.local_folder.to_string_lossy().as_ref())
+    }
+
+    /// Makes sure the workspace's container is running, reusing an existing one
+    /// if there is any, and otherwise building or pulling the configured image
+    /// and creating one from it.
+    pub async fn run(&self, docker: &DockerClient, docker_host: &str) -> Result<RunningContainer> {
+        let mut existing = docker
+            .list_containers(true)
+            .await?
+            .into_iter()
+            .filter(|container| self.owns(container))
+            .collect::<Vec<_>>();
+        // Prefer a running container, in case there are several.
+        existing.sort_by_key(|container| container.state != ContainerState::Running);
+        if let Some(container) = existing.into_iter().next() {
+            return match container.state {
+                ContainerState::Running => Ok(RunningContainer::Existing(container.id)),
+                ContainerState::Paused => {
+                    docker.unpause_container(&container.id).await?;
+                    Ok(RunningContainer::Started(container.id))
+                }
+                _ => {
+                    docker.start_container(&container.id).await?;
+                    Ok(RunningContainer::Started(container.id))
+                }
+            };
+        }
+
+        let image = self.resolve_image(docker, docker_host, |_| {}).await?;
+        let id = self.start_container(docker, image).await?;
+        Ok(RunningContainer::Created(id))
+    }
+
+    /// Removes the workspace's existing dev containers, then builds or pulls the
+    /// image and starts a new container from it, sending the build or pull's
+    /// output to `progress`. Returns the new container's id.
+    pub async fn rebuild(
+        &self,
+        docker: &DockerClient,
+        docker_host: &str,
+        progress: mpsc::UnboundedSender<String>,
+    ) -> Result<String> {
+        let report = |line: String| {
+            progress.unbounded_send(line).ok();
+        };
+
+        for container in docker.list_containers(true).await? {
+            if !self.owns(&container) {
+                continue;
+            }
+            let short_id = &container.id[..container.id.len().min(12)];
+            if container.state == ContainerState::Running {
+                report(format!("Stopping container {short_id}"));
+                docker.stop_container(&container.id).await?;
+            }
+            report(format!("Removing container {short_id}"));
+            docker.remove_container(&container.id, true).await?;
+        }
+
+        let image = self.resolve_image(docker, docker_host, &report).await?;
+        report(format!("Starting a container from {image}"));
+        self.start_container(docker, image).await
+    }
+
+    /// Builds the configured Dockerfile, tagging the image with [`Self::image_tag`]
+    /// so rebuilding replaces it, or pulls the configured image, sending the
+    /// output to `report`. Returns the image to start containers from.
+    async fn resolve_image(
+        &self,
+        docker: &DockerClient,
+        docker_host: &str,
+        report: impl Fn(String),
+    ) -> Result<String> {
+        let config_dir = self.config_dir()?;
+        if let Some(dockerfile) = self.config.dockerfile() {
+            let tag = self.image_tag();
+            report(format!("Building {tag}"));
+            let mut process = docker::build_image(
+                docker_host,
+                &config_dir.join(dockerfile),
+                &tag,
+                &config_dir.join(self.config.build_context()),
+                &self.config.build_args(),
+            )?;
+            while let Some(line) = process.next_line().await? {
+                report(line);
+            }
+            process.wait().await?;
+            Ok(tag)
+        } else if let Some(image) = &self.config.image {
+            report(format!("Pulling {image}"));
+            let mut pull = docker.pull_image_with_progress(image).await?;
+            while let Some(pull_progress) = pull.next_progress().await? {
+                report(pull_progress.to_string());
+            }
+            Ok(image.clone())
+        } else {
+            Err(self.missing_image_error())
+        }
+    }
+
+    async fn start_container(&self, docker: &DockerClient, image: String) -> Result<String> {
+        let labels = HashMap::from_iter([
+            (
+                LOCAL_FOLDER_LABEL.to_string(),
+                self.local_folder.to_string_lossy().into_owned(),
+            ),
+            (
+                CONFIG_FILE_LABEL.to_string(),
+                self.config_path.to_string_lossy().into_owned(),
+            ),
+        ]);
+        let mut host_config = HostConfig::default();
+        for (host_port, container_port) in self.config.app_ports() {
+            host_config
+                .port_bindings
+                .entry(format!("{container_port}/tcp"))
+                .or_default()
+                .push(PortBinding {
+                    host_ip: String::new(),
+                    host_port: host_port.to_string(),
+                });
+        }
+        host_config.mounts = self.mounts();
+        let id = docker
+            .create_container(&CreateContainerRequest {
+                image,
+                cmd: KEEP_ALIVE_COMMAND.map(String::from).to_vec(),
+                labels,
+                exposed_ports: host_config
+                    .port_bindings
+                    .keys()
+                    .map(|port| (port.clone(), Default::default()))
+                    .collect(),
+                working_dir: Some(self.workspace_folder()),
+                host_config: Some(host_config),
+            })
+            .await?;
+        docker.start_container(&id).await?;
+        Ok(id)
+    }
+}
+
+/// Returns the dev container configuration of the first worktree root that has one.
+pub async fn find_dev_container(
+    fs: &dyn Fs,
+    worktree_roots: &[Arc<Path>],
+) -> Result<Option<DevContainer>> {
+    for root in worktree_roots {
+        for config_path in CONFIG_PATHS {
+            let config_path = root.join(config_path);
+            if !fs.is_file(&config_path).await {
+                continue;
+            }
+
+            let content = fs.load(&config_path).await?;
+            let config = DevContainerConfig::parse(&content)
+                .with_context(|| format!("failed to parse {}", config_path.display()))?;
+            return Ok(Some(DevContainer {
+                local_folder: root.to_path_buf(),
+                config_path,
+                config,
+            }));
+        }
+    }
+    Ok(None)
+}
+
+/// Whether a change to `path` may affect the dev container configuration.
+pub fn is_config_path(path: &Path) -> bool {
+    CONFIG_PATHS
+        .iter()
+        .any(|config_path| path == Path::new(config_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config = DevContainerConfig::parse(
+            r#"{
+                // Comments and trailing commas are allowed.
+                "name": "Rust",
+                "image": "mcr.microsoft.com/devcontainers/rust:1",
+                "forwardPorts": [3000, "db:5432"],
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            DevContainerConfig {
+                name: Some("Rust".into()),
+                image: Some("mcr.microsoft.com/devcontainers/rust:1".into()),
+                docker_file: None,
+                context: None,
+                build: None,
+                forward_ports: vec![
+                    ForwardPort::Port(3000),
+                    ForwardPort::Address("db:5432".into())
+                ],
+                app_port: None,
+                post_create_command: None,
+                post_start_command: None,
+                workspace_folder: None,
+                mounts: Vec::new(),
+            }
+        );
+
+        let config = DevContainerConfig::parse(r#"{ "dockerFile": "Dockerfile" }"#).unwrap();
+        assert_eq!(config.docker_file.as_deref(), Some("Dockerfile"));
+        assert!(config.forward_ports.is_empty());
+    }
+
+    #[test]
+    fn test_lifecycle_commands() {
+        let config = DevContainerConfig::parse(
+            r#"{
+                "postCreateCommand": "cargo fetch && npm ci",
+                "postStartCommand": ["git", "fetch", "--all"]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.post_create_command.unwrap().argv(),
+            ["/bin/sh", "-c", "cargo fetch && npm ci"]
+        );
+        assert_eq!(
+            config.post_start_command.unwrap().argv(),
+            ["git", "fetch", "--all"]
+        );
+    }
+
+    #[test]
+    fn test_build_config() {
+        let config = DevContainerConfig::parse(
+            r#"{
+                "dockerFile": "Dockerfile.old",
+                "build": {
+                    "dockerfile": "Dockerfile",
+                    "context": "..",
+                    "args": { "VARIANT": "bookworm", "NODE_VERSION": "20" }
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(config.dockerfile(), Some("Dockerfile"));
+        assert_eq!(config.build_context(), "..");
+        assert_eq!(
+            config.build_args(),
+            vec![
+                "NODE_VERSION=20".to_string(),
+                "VARIANT=bookworm".to_string()
+            ]
+        );
+
+        let config = DevContainerConfig::parse(r#"{ "dockerFile": "Dockerfile" }"#).unwrap();
+        assert_eq!(config.dockerfile(), Some("Dockerfile"));
+        assert_eq!(config.build_context(), ".");
+        assert!(config.build_args().is_empty());
+    }
+
+    #[test]
+    fn test_ports_to_forward() {
+        let config = DevContainerConfig::parse(
+            r#"{
+                "forwardPorts": [3000, "localhost:8080", "db:5432"],
+                "appPort": [3000, "9000:9001", "not a port"]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(config.app_ports(), vec![(3000, 3000), (9000, 9001)]);
+        assert_eq!(config.ports_to_forward(), vec![3000, 8080, 9001]);
+
+        let config = DevContainerConfig::parse(r#"{ "appPort": "8000" }"#).unwrap();
+        assert_eq!(config.app_ports(), vec![(8000, 8000)]);
+        let config = DevContainerConfig::parse(r#"{ "appPort": 8000 }"#).unwrap();
+        assert_eq!(config.ports_to_forward(), vec![8000]);
+    }
+
+    #[test]
+    fn test_image_tag() {
+        let dev_container = DevContainer {
+            local_folder: PathBuf::from("/home/user/my-app"),
+            config_path: PathBuf::from("/home/user/my-app/.devcontainer/devcontainer.json"),
+            config: DevContainerConfig {
+                name: Some("Rust & Node (Dev)".into()),
+                ..Default::default()
+            },
+        };
+        assert_eq!(
+            dev_container.image_tag(),
+            "zed-devcontainer-rust---node--dev"
+        );
+    }
+
+    #[test]
+    fn test_mounts() {
+        let mut dev_container = DevContainer {
+            local_folder: PathBuf::from("/home/user/my-app"),
+            config_path: PathBuf::from("/home/user/my-app/.devcontainer/devcontainer.json"),
+            config: DevContainerConfig::default(),
+        };
+        assert_eq!(dev_container.workspace_folder(), "/workspaces/my-app");
+        assert_eq!(
+            dev_container.mounts(),
+            [MountSpec {
+                target: "/workspaces/my-app".into(),
+                source: "/home/user/my-app".into(),
+                kind: "bind".into(),
+                read_only: false,
+            }]
+        );
+
+        dev_container.config = DevContainerConfig::parse(
+            r#"{
+                "workspaceFolder": "/src/${localWorkspaceFolderBasename}",
+                "mounts": [
+                    "source=${localWorkspaceFolderBasename}-cache,target=${containerWorkspaceFolder}/target",
+                    "type=bind,src=${localEnv:ZED_DEVCONTAINER_TEST_UNSET:/tmp},dst=/host-tmp,readonly",
+                    { "source": "history", "target": "/commandhistory", "type": "volume" }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(dev_container.workspace_folder(), "/src/my-app");
+        assert_eq!(
+            dev_container.mounts(),
+            [
+                MountSpec {
+                    target: "/src/my-app".into(),
+                    source: "/home/user/my-app".into(),
+                    kind: "bind".into(),
+                    read_only: false,
+                },
+                MountSpec {
+                    target: "/src/my-app/target".into(),
+                    source: "my-app-cache".into(),
+                    kind: "volume".into(),
+                    read_only: false,
+                },
+                MountSpec {
+                    target: "/host-tmp".into(),
+                    source: "/tmp".into(),
+                    kind: "bind".into(),
+                    read_only: true,
+                },
+                MountSpec {
+                    target: "/commandhistory".into(),
+                    source: "history".into(),
+                    kind: "volume".into(),
+                    read_only: false,
+                },
+            ]
+        );
+
+        // A configured mount at the workspace folder replaces the workspace's.
+        dev_container.config = DevContainerConfig::parse(
+            r#"{ "mounts": ["source=/data/my-app,target=/workspaces/my-app,type=bind"] }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            dev_container.mounts(),
+            [MountSpec {
+                target: "/workspaces/my-app".into(),
+                source: "/data/my-app".into(),
+                kind: "bind".into(),
+                read_only: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_is_config_path() {
+        assert!(is_config_path(Path::new(".devcontainer/devcontainer.json")));
+        assert!(is_config_path(Path::new(".devcontainer.json")));
+        assert!(!is_config_path(Path::new("src/devcontainer.json")));
+    }
+}
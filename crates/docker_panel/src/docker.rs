@@ -0,0 +1,1583 @@
+use anyhow::{anyhow, Context, Result};
+use async_tar::{Archive, Entry};
+use collections::HashMap;
+use futures::{io::BufReader, AsyncBufReadExt, AsyncRead, AsyncReadExt, Stream, StreamExt};
+use isahc::{
+    config::{Configurable, Dialer},
+    http::{request::Builder, Method, StatusCode},
+    AsyncBody, HttpClient, Request, Response,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    ffi::OsStr,
+    fmt, io,
+    net::IpAddr,
+    path::Path,
+    pin::Pin,
+    process::Stdio,
+    sync::{
+        atomic::{self, AtomicU64},
+        Arc,
+    },
+    task::{Context as TaskContext, Poll},
+    time::Duration,
+};
+
+pub const DEFAULT_DOCKER_HOST: &str = "unix:///var/run/docker.sock";
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const LOG_TAIL_LINES: usize = 1000;
+
+/// A minimal client for the Docker Engine API.
+///
+/// See <https://docs.docker.com/engine/api/> for the endpoints used here.
+pub struct DockerClient {
+    http: HttpClient,
+    base_url: String,
+    dialer: Option<Dialer>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContainerSummary {
+    pub id: String,
+    #[serde(default)]
+    pub names: Vec<String>,
+    pub image: String,
+    /// When the container was created, in seconds since the Unix epoch.
+    pub created: i64,
+    pub state: ContainerState,
+    pub status: String,
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+    #[serde(default)]
+    pub ports: Vec<Port>,
+    #[serde(default)]
+    pub mounts: Vec<Mount>,
+    /// The size of the container's writable layer. Only reported when sizes are requested.
+    pub size_rw: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ImageSummary {
+    pub id: String,
+    /// References such as `rust:1.78`. Untagged images have none, or a single
+    /// `<none>:<none>`, depending on the engine version.
+    #[serde(default)]
+    pub repo_tags: Option<Vec<String>>,
+    /// When the image was created, in seconds since the Unix epoch.
+    pub created: i64,
+    pub size: u64,
+}
+
+/// The state of a container's `HEALTHCHECK`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HealthStatus {
+    Starting,
+    Healthy,
+    Unhealthy,
+}
+
+impl HealthStatus {
+    /// Parses the health the engine appends to a container's status, e.g.
+    /// `Up 5 minutes (healthy)`. Containers without a health check have none.
+    pub fn from_status(status: &str) -> Option<Self> {
+        if status.ends_with("(healthy)") {
+            Some(HealthStatus::Healthy)
+        } else if status.ends_with("(unhealthy)") {
+            Some(HealthStatus::Unhealthy)
+        } else if status.ends_with("(health: starting)") {
+            Some(HealthStatus::Starting)
+        } else {
+            None
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            HealthStatus::Starting => "Starting",
+            HealthStatus::Healthy => "Healthy",
+            HealthStatus::Unhealthy => "Unhealthy",
+        }
+    }
+}
+
+/// The parts of `GET /containers/{id}/json` the panel reads.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ContainerInspect {
+    state: ContainerInspectState,
+    #[serde(default)]
+    network_settings: NetworkSettings,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct NetworkSettings {
+    /// The address on the default bridge network, empty when not connected to it.
+    #[serde(default, rename = "IPAddress")]
+    ip_address: String,
+    #[serde(default)]
+    networks: HashMap<String, EndpointSettings>,
+}
+
+#[derive(Deserialize)]
+struct EndpointSettings {
+    #[serde(default, rename = "IPAddress")]
+    ip_address: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ContainerInspectState {
+    health: Option<Health>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Health {
+    #[serde(default)]
+    log: Vec<HealthCheckResult>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct HealthCheckResult {
+    output: String,
+}
+
+/// A volume, bind mount or tmpfs mounted into a container.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Mount {
+    /// `volume`, `bind`, `tmpfs` or `npipe`.
+    #[serde(rename = "Type")]
+    pub kind: String,
+    /// The volume's name, for volume mounts.
+    pub name: Option<String>,
+    #[serde(default)]
+    pub source: String,
+    pub destination: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct VolumeSummary {
+    pub name: String,
+    pub driver: String,
+    pub mountpoint: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VolumeListResponse {
+    /// `null` rather than empty when there are no volumes.
+    volumes: Option<Vec<VolumeSummary>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VolumePruneResponse {
+    volumes_deleted: Option<Vec<String>>,
+    space_reclaimed: u64,
+}
+
+/// A port exposed by a container.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Port {
+    /// The host address the port is published on.
+    #[serde(rename = "IP")]
+    pub ip: Option<String>,
+    pub private_port: u16,
+    /// Unset unless the port is published on the host.
+    pub public_port: Option<u16>,
+    #[serde(rename = "Type")]
+    pub protocol: PortProtocol,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PortProtocol {
+    Tcp,
+    Udp,
+    Sctp,
+    #[serde(other)]
+    Unknown,
+}
+
+impl PortProtocol {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PortProtocol::Tcp => "tcp",
+            PortProtocol::Udp => "udp",
+            PortProtocol::Sctp => "sctp",
+            PortProtocol::Unknown => "unknown",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContainerState {
+    Created,
+    Running,
+    Paused,
+    Restarting,
+    Removing,
+    Exited,
+    Dead,
+    #[serde(other)]
+    Unknown,
+}
+
+/// The body of `POST /containers/create`.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateContainerRequest {
+    pub image: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub cmd: Vec<String>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub labels: HashMap<String, String>,
+    /// Keyed by `port/protocol`, e.g. `8080/tcp`.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub exposed_ports: HashMap<String, EmptyObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_dir: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host_config: Option<HostConfig>,
+}
+
+/// Serializes as `{}`, for the maps the Engine API uses as sets.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct EmptyObject {}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HostConfig {
+    /// Keyed by `port/protocol`, like [`CreateContainerRequest::exposed_ports`].
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub port_bindings: HashMap<String, Vec<PortBinding>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub mounts: Vec<MountSpec>,
+}
+
+/// A mount to create along with a container, unlike [`Mount`], which describes
+/// the mounts of an existing one.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MountSpec {
+    pub target: String,
+    /// The host path of bind mounts or the name of volume mounts.
+    pub source: String,
+    /// `bind`, `volume` or `tmpfs`.
+    #[serde(rename = "Type")]
+    pub kind: String,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub read_only: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PortBinding {
+    /// Empty to publish on all interfaces.
+    pub host_ip: String,
+    pub host_port: String,
+}
+
+/// The response of endpoints that create something, such as `POST /containers/create`.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct IdResponse {
+    id: String,
+}
+
+/// The body of `POST /containers/{id}/exec`.
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct CreateExecRequest<'a> {
+    cmd: &'a [String],
+    attach_stdout: bool,
+    attach_stderr: bool,
+}
+
+/// The body of `POST /exec/{id}/start`.
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct StartExecRequest {
+    detach: bool,
+}
+
+/// The response of `GET /exec/{id}/json`.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ExecInspect {
+    running: bool,
+    exit_code: Option<i64>,
+}
+
+/// The body of `POST /containers/prune`.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ContainerPruneResponse {
+    /// `null` rather than empty when nothing was removed.
+    containers_deleted: Option<Vec<String>>,
+    space_reclaimed: u64,
+}
+
+/// What a prune removed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PruneReport {
+    pub removed: usize,
+    pub space_reclaimed: u64,
+}
+
+/// The body of `POST /images/prune`.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ImagePruneResponse {
+    /// `null` rather than empty when nothing was removed.
+    images_deleted: Option<Vec<ImageDeleteResponseItem>>,
+    space_reclaimed: u64,
+}
+
+/// Removing an image untags each of its references, then deletes its layers.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ImageDeleteResponseItem {
+    deleted: Option<String>,
+}
+
+/// The body of `GET /system/df`. Sizes are `-1` when they haven't been computed.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SystemDfResponse {
+    layers_size: i64,
+    images: Option<Vec<DfImage>>,
+    containers: Option<Vec<DfContainer>>,
+    volumes: Option<Vec<DfVolume>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct DfImage {
+    size: i64,
+    shared_size: i64,
+    /// The number of containers using the image.
+    containers: i64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct DfContainer {
+    state: ContainerState,
+    #[serde(default)]
+    size_rw: i64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct DfVolume {
+    usage_data: Option<VolumeUsageData>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VolumeUsageData {
+    size: i64,
+    ref_count: i64,
+}
+
+/// The disk space used by the daemon's images, containers and volumes, like
+/// `docker system df`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DiskUsage {
+    pub images: u64,
+    pub containers: u64,
+    pub volumes: u64,
+    /// What removing stopped containers, then unused images and volumes would
+    /// free.
+    pub reclaimable: u64,
+}
+
+impl From<SystemDfResponse> for DiskUsage {
+    fn from(response: SystemDfResponse) -> Self {
+        fn size(size: i64) -> u64 {
+            size.max(0) as u64
+        }
+
+        let mut usage = DiskUsage {
+            images: size(response.layers_size),
+            ..Default::default()
+        };
+        for image in response.images.unwrap_or_default() {
+            if image.containers == 0 {
+                // Layers shared with other images are only freed along with them.
+                usage.reclaimable += size(image.size - image.shared_size.max(0));
+            }
+        }
+        for container in response.containers.unwrap_or_default() {
+            usage.containers += size(container.size_rw);
+            if container.state != ContainerState::Running
+                && container.state != ContainerState::Paused
+            {
+                usage.reclaimable += size(container.size_rw);
+            }
+        }
+        for usage_data in response
+            .volumes
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|volume| volume.usage_data)
+        {
+            usage.volumes += size(usage_data.size);
+            if usage_data.ref_count == 0 {
+                usage.reclaimable += size(usage_data.size);
+            }
+        }
+        usage
+    }
+}
+
+/// One of the progress messages streamed by `POST /images/create`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct PullProgress {
+    /// The layer the message is about, if any.
+    pub id: Option<String>,
+    #[serde(default)]
+    pub status: String,
+    /// A textual progress bar, e.g. `[=====>    ] 12.3MB/45.6MB`.
+    pub progress: Option<String>,
+    error: Option<String>,
+}
+
+/// Formats the message like the Docker CLI, e.g. `a1b2c3: Downloading [==>  ] 1MB/2MB`.
+impl fmt::Display for PullProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(id) = &self.id {
+            write!(f, "{id}: ")?;
+        }
+        write!(f, "{}", self.status)?;
+        if let Some(progress) = &self.progress {
+            write!(f, " {progress}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A `POST /images/create` response, streaming the pull's progress.
+pub struct PullStream {
+    reference: String,
+    lines: JsonLines,
+}
+
+impl PullStream {
+    /// Waits for the next progress message, returning `None` once the pull has
+    /// completed. Failures that happen after the pull has started are reported
+    /// in the stream rather than through the status code.
+    pub async fn next_progress(&mut self) -> Result<Option<PullProgress>> {
+        let Some(line) = self.lines.next_line().await? else {
+            return Ok(None);
+        };
+        let progress: PullProgress = serde_json::from_slice(&line)?;
+        if let Some(error) = progress.error {
+            return Err(anyhow!("failed to pull {}: {error}", self.reference));
+        }
+        Ok(Some(progress))
+    }
+}
+
+/// A single sample from `GET /containers/{id}/stats`.
+#[derive(Debug, Deserialize)]
+pub struct ContainerStatsResponse {
+    cpu_stats: CpuStats,
+    precpu_stats: CpuStats,
+    memory_stats: MemoryStats,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct CpuStats {
+    cpu_usage: CpuUsage,
+    system_cpu_usage: Option<u64>,
+    online_cpus: Option<u32>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct CpuUsage {
+    total_usage: u64,
+    percpu_usage: Option<Vec<u64>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct MemoryStats {
+    usage: Option<u64>,
+    limit: Option<u64>,
+    stats: Option<MemoryStatsDetails>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct MemoryStatsDetails {
+    /// Reported by cgroup v1.
+    cache: Option<u64>,
+    /// Reported by cgroup v2.
+    inactive_file: Option<u64>,
+}
+
+impl ContainerStatsResponse {
+    /// Computes the CPU usage the same way `docker stats` does, where 100%
+    /// corresponds to one fully used core.
+    pub fn cpu_percent(&self) -> f32 {
+        let cpu_delta = self
+            .cpu_stats
+            .cpu_usage
+            .total_usage
+            .saturating_sub(self.precpu_stats.cpu_usage.total_usage);
+        let system_delta = self
+            .cpu_stats
+            .system_cpu_usage
+            .unwrap_or(0)
+            .saturating_sub(self.precpu_stats.system_cpu_usage.unwrap_or(0));
+        if cpu_delta == 0 || system_delta == 0 {
+            return 0.;
+        }
+
+        let online_cpus = self.cpu_stats.online_cpus.unwrap_or_else(|| {
+            self.cpu_stats
+                .cpu_usage
+                .percpu_usage
+                .as_ref()
+                .map_or(1, |usage| usage.len() as u32)
+        });
+        (cpu_delta as f64 / system_delta as f64 * online_cpus as f64 * 100.) as f32
+    }
+
+    /// Returns the memory in use, excluding the page cache, as `docker stats` does.
+    pub fn memory_usage(&self) -> u64 {
+        let usage = self.memory_stats.usage.unwrap_or(0);
+        let cache = self
+            .memory_stats
+            .stats
+            .as_ref()
+            .and_then(|stats| stats.inactive_file.or(stats.cache))
+            .unwrap_or(0);
+        usage.saturating_sub(cache)
+    }
+
+    pub fn memory_limit(&self) -> u64 {
+        self.memory_stats.limit.unwrap_or(0)
+    }
+}
+
+/// A followed `GET /containers/{id}/logs` response.
+pub struct LogStream {
+    body: AsyncBody,
+    decoder: LogDecoder,
+}
+
+impl LogStream {
+    /// Waits for the next batch of complete lines, returning `None` once the
+    /// container's output has ended.
+    pub async fn next_lines(&mut self) -> Result<Option<Vec<String>>> {
+        let mut buffer = [0; 8192];
+        loop {
+            let count = self.body.read(&mut buffer).await?;
+            if count == 0 {
+                return Ok(self.decoder.finish().map(|line| vec![line]));
+            }
+
+            let lines = self.decoder.push(&buffer[..count]);
+            if !lines.is_empty() {
+                return Ok(Some(lines));
+            }
+        }
+    }
+}
+
+/// The output of a command run in a container by `exec`.
+pub struct ExecStream {
+    pub exec_id: String,
+    output: LogStream,
+}
+
+impl ExecStream {
+    /// Waits for the next batch of complete lines, returning `None` once the
+    /// command has exited.
+    pub async fn next_lines(&mut self) -> Result<Option<Vec<String>>> {
+        self.output.next_lines().await
+    }
+}
+
+/// A file being read out of a container by `GET /containers/{id}/archive`, which
+/// sends it as the only entry of a tar archive.
+pub struct ContainerFile {
+    entry: Pin<Box<Entry<Archive<AsyncBody>>>>,
+    size: u64,
+    bytes_read: Arc<AtomicU64>,
+}
+
+impl ContainerFile {
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Counts the bytes read so far, for reporting progress while the file is
+    /// being read elsewhere.
+    pub fn bytes_read(&self) -> Arc<AtomicU64> {
+        self.bytes_read.clone()
+    }
+}
+
+impl AsyncRead for ContainerFile {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let poll = self.entry.as_mut().poll_read(cx, buf);
+        if let Poll::Ready(Ok(count)) = &poll {
+            self.bytes_read
+                .fetch_add(*count as u64, atomic::Ordering::Relaxed);
+        }
+        poll
+    }
+}
+
+/// A container lifecycle event from `GET /events`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ContainerEvent {
+    Started {
+        id: String,
+        name: String,
+    },
+    /// The container's process was sent a signal, typically on its way to stopping.
+    Killed {
+        id: String,
+    },
+    Stopped {
+        id: String,
+        name: String,
+    },
+    Died {
+        id: String,
+        name: String,
+        exit_code: i64,
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawEvent {
+    #[serde(rename = "Type")]
+    kind: String,
+    action: String,
+    actor: RawActor,
+}
+
+#[derive(Deserialize)]
+struct RawActor {
+    #[serde(rename = "ID")]
+    id: String,
+    #[serde(rename = "Attributes", default)]
+    attributes: HashMap<String, String>,
+}
+
+/// The events the panel subscribes to; see [`ContainerEvent`].
+const CONTAINER_EVENTS: [&str; 4] = ["start", "kill", "stop", "die"];
+
+impl ContainerEvent {
+    /// Parses a single event, returning `None` for events the panel doesn't handle.
+    fn parse(json: &[u8]) -> Result<Option<Self>> {
+        let event: RawEvent = serde_json::from_slice(json)?;
+        if event.kind != "container" {
+            return Ok(None);
+        }
+
+        let RawActor { id, mut attributes } = event.actor;
+        let name = attributes.remove("name").unwrap_or_else(|| id.clone());
+        Ok(match event.action.as_str() {
+            "start" => Some(ContainerEvent::Started { id, name }),
+            "kill" => Some(ContainerEvent::Killed { id }),
+            "stop" => Some(ContainerEvent::Stopped { id, name }),
+            "die" => {
+                let exit_code = attributes
+                    .get("exitCode")
+                    .and_then(|code| code.parse().ok())
+                    .unwrap_or(0);
+                Some(ContainerEvent::Died {
+                    id,
+                    name,
+                    exit_code,
+                })
+            }
+            _ => None,
+        })
+    }
+}
+
+/// A response body that streams one JSON object per line.
+struct JsonLines {
+    body: AsyncBody,
+    pending: Vec<u8>,
+}
+
+impl JsonLines {
+    fn new(body: AsyncBody) -> Self {
+        Self {
+            body,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Waits for the next non-blank line, returning `None` once the body ends.
+    async fn next_line(&mut self) -> Result<Option<Vec<u8>>> {
+        let mut buffer = [0; 4096];
+        loop {
+            while let Some(newline) = self.pending.iter().position(|byte| *byte == b'\n') {
+                let line = self.pending.drain(..=newline).collect::<Vec<_>>();
+                if !line.iter().all(u8::is_ascii_whitespace) {
+                    return Ok(Some(line));
+                }
+            }
+
+            let count = self.body.read(&mut buffer).await?;
+            if count == 0 {
+                let line = std::mem::take(&mut self.pending);
+                return Ok((!line.iter().all(u8::is_ascii_whitespace)).then_some(line));
+            }
+            self.pending.extend_from_slice(&buffer[..count]);
+        }
+    }
+}
+
+/// The body of `GET /events`.
+pub struct EventStream {
+    lines: JsonLines,
+}
+
+impl EventStream {
+    /// Waits for the next container event, returning `None` once the daemon
+    /// closes the stream.
+    pub async fn next_event(&mut self) -> Result<Option<ContainerEvent>> {
+        while let Some(line) = self.lines.next_line().await? {
+            if let Some(event) = ContainerEvent::parse(&line)? {
+                return Ok(Some(event));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Splits container output into lines.
+///
+/// Containers without a TTY have their stdout and stderr multiplexed into
+/// frames, each with an 8 byte header: the stream type, three zero bytes, and
+/// the big-endian payload length. Containers with a TTY send raw output.
+#[derive(Default)]
+struct LogDecoder {
+    multiplexed: Option<bool>,
+    frames: Vec<u8>,
+    pending_line: Vec<u8>,
+}
+
+impl LogDecoder {
+    const HEADER_LEN: usize = 8;
+
+    fn push(&mut self, bytes: &[u8]) -> Vec<String> {
+        self.frames.extend_from_slice(bytes);
+        if self.multiplexed.is_none() {
+            if self.frames.len() < Self::HEADER_LEN {
+                return Vec::new();
+            }
+            let header = &self.frames[..Self::HEADER_LEN];
+            self.multiplexed = Some(header[0] <= 2 && header[1..4] == [0, 0, 0]);
+        }
+
+        if self.multiplexed == Some(true) {
+            let mut offset = 0;
+            while self.frames.len() - offset >= Self::HEADER_LEN {
+                let header = &self.frames[offset..offset + Self::HEADER_LEN];
+                let len = u32::from_be_bytes([header[4], header[5], header[6], header[7]]) as usize;
+                let frame_end = offset + Self::HEADER_LEN + len;
+                if self.frames.len() < frame_end {
+                    break;
+                }
+                self.pending_line
+                    .extend_from_slice(&self.frames[offset + Self::HEADER_LEN..frame_end]);
+                offset = frame_end;
+            }
+            self.frames.drain(..offset);
+        } else {
+            self.pending_line.append(&mut self.frames);
+        }
+
+        let mut lines = Vec::new();
+        while let Some(newline) = self.pending_line.iter().position(|byte| *byte == b'\n') {
+            let line = self.pending_line.drain(..=newline).collect::<Vec<_>>();
+            lines.push(Self::decode_line(&line));
+        }
+        lines
+    }
+
+    /// Flushes a trailing line that was not terminated by a newline.
+    fn finish(&mut self) -> Option<String> {
+        if self.multiplexed != Some(true) {
+            self.pending_line.append(&mut self.frames);
+        }
+        if self.pending_line.is_empty() {
+            None
+        } else {
+            let line = std::mem::take(&mut self.pending_line);
+            Some(Self::decode_line(&line))
+        }
+    }
+
+    fn decode_line(line: &[u8]) -> String {
+        String::from_utf8_lossy(line)
+            .trim_end_matches(['\n', '\r'])
+            .to_string()
+    }
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    message: String,
+}
+
+impl DockerClient {
+    /// Creates a client for a Docker host such as `unix:///var/run/docker.sock`
+    /// or `tcp://localhost:2375`.
+    pub fn new(host: &str) -> Result<Self> {
+        let http = HttpClient::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .build()?;
+
+        #[cfg(unix)]
+        if let Some(socket_path) = host.strip_prefix("unix://") {
+            return Ok(Self {
+                http,
+                base_url: "http://localhost".into(),
+                dialer: Some(Dialer::unix_socket(socket_path)),
+            });
+        }
+
+        if let Some(address) = host.strip_prefix("tcp://") {
+            Ok(Self {
+                http,
+                base_url: format!("http://{}", address.trim_end_matches('/')),
+                dialer: None,
+            })
+        } else {
+            Err(anyhow!("unsupported Docker host {host:?}"))
+        }
+    }
+
+    pub async fn list_containers(&self, all: bool) -> Result<Vec<ContainerSummary>> {
+        self.get_json(&format!("/containers/json?all={all}")).await
+    }
+
+    pub async fn list_images(&self) -> Result<Vec<ImageSummary>> {
+        self.get_json("/images/json").await
+    }
+
+    /// Removes an image by id, or untags it when given one of several references to it.
+    pub async fn remove_image(&self, reference: &str) -> Result<()> {
+        // References only contain URL-safe characters, and their slashes are part of the path.
+        let request = self
+            .request(Method::DELETE, &format!("/images/{reference}"))
+            .body(AsyncBody::empty())?;
+        self.send(request).await?;
+        Ok(())
+    }
+
+    pub async fn list_volumes(&self) -> Result<Vec<VolumeSummary>> {
+        let response: VolumeListResponse = self.get_json("/volumes").await?;
+        Ok(response.volumes.unwrap_or_default())
+    }
+
+    pub async fn remove_volume(&self, name: &str) -> Result<()> {
+        let request = self
+            .request(Method::DELETE, &format!("/volumes/{name}"))
+            .body(AsyncBody::empty())?;
+        self.send(request).await?;
+        Ok(())
+    }
+
+    /// Removes the images no container uses, including tagged ones, like
+    /// `docker image prune --all`.
+    pub async fn prune_images(&self) -> Result<PruneReport> {
+        let filters = serde_json::json!({ "dangling": ["false"] }).to_string();
+        let filters: String = url::form_urlencoded::byte_serialize(filters.as_bytes()).collect();
+        let request = self
+            .request(Method::POST, &format!("/images/prune?filters={filters}"))
+            .body(AsyncBody::empty())?;
+        let response: ImagePruneResponse = self.read_json(request).await?;
+        Ok(PruneReport {
+            removed: response
+                .images_deleted
+                .unwrap_or_default()
+                .iter()
+                .filter(|item| item.deleted.is_some())
+                .count(),
+            space_reclaimed: response.space_reclaimed,
+        })
+    }
+
+    pub async fn disk_usage(&self) -> Result<DiskUsage> {
+        let response: SystemDfResponse = self.get_json("/system/df").await?;
+        Ok(response.into())
+    }
+
+    /// Removes the volumes no container uses, like `docker volume prune`. Recent
+    /// engines only prune anonymous volumes.
+    pub async fn prune_volumes(&self) -> Result<PruneReport> {
+        let request = self
+            .request(Method::POST, "/volumes/prune")
+            .body(AsyncBody::empty())?;
+        let response: VolumePruneResponse = self.read_json(request).await?;
+        Ok(PruneReport {
+            removed: response.volumes_deleted.map_or(0, |deleted| deleted.len()),
+            space_reclaimed: response.space_reclaimed,
+        })
+    }
+
+    /// Looks up a single container, returning `None` if it no longer exists.
+    pub async fn container(&self, id: &str) -> Result<Option<ContainerSummary>> {
+        let filters = serde_json::json!({ "id": [id] }).to_string();
+        let filters: String = url::form_urlencoded::byte_serialize(filters.as_bytes()).collect();
+        let containers: Vec<ContainerSummary> = self
+            .get_json(&format!("/containers/json?all=true&filters={filters}"))
+            .await?;
+        Ok(containers.into_iter().next())
+    }
+
+    /// Returns the container's low-level information, as shown by `docker inspect`,
+    /// pretty-printed.
+    pub async fn inspect_container(&self, id: &str) -> Result<String> {
+        let info: serde_json::Value = self.get_json(&format!("/containers/{id}/json")).await?;
+        Ok(serde_json::to_string_pretty(&info)?)
+    }
+
+    /// Returns the container's address on one of its networks, if it's running
+    /// and connected to any.
+    pub async fn container_ip(&self, id: &str) -> Result<Option<IpAddr>> {
+        let info: ContainerInspect = self.get_json(&format!("/containers/{id}/json")).await?;
+        let NetworkSettings {
+            ip_address,
+            networks,
+        } = info.network_settings;
+        Ok(std::iter::once(ip_address)
+            .chain(networks.into_values().map(|network| network.ip_address))
+            .find_map(|address| address.parse().ok()))
+    }
+
+    /// Returns the last line of output of the container's latest health check.
+    pub async fn last_health_check_output(&self, id: &str) -> Result<Option<String>> {
+        let info: ContainerInspect = self.get_json(&format!("/containers/{id}/json")).await?;
+        Ok(info
+            .state
+            .health
+            .and_then(|health| health.log.into_iter().last())
+            .and_then(|result| {
+                let line = result.output.trim().lines().last()?.trim().to_string();
+                (!line.is_empty()).then_some(line)
+            }))
+    }
+
+    pub async fn start_container(&self, id: &str) -> Result<()> {
+        self.post(&format!("/containers/{id}/start")).await
+    }
+
+    pub async fn stop_container(&self, id: &str) -> Result<()> {
+        self.post(&format!("/containers/{id}/stop")).await
+    }
+
+    pub async fn restart_container(&self, id: &str) -> Result<()> {
+        self.post(&format!("/containers/{id}/restart")).await
+    }
+
+    /// Sends a signal such as `SIGTERM` to the container's main process.
+    pub async fn kill_container(&self, id: &str, signal: &str) -> Result<()> {
+        self.post(&format!("/containers/{id}/kill?signal={signal}"))
+            .await
+    }
+
+    pub async fn rename_container(&self, id: &str, name: &str) -> Result<()> {
+        let encoded: String = url::form_urlencoded::byte_serialize(name.as_bytes()).collect();
+        self.post(&format!("/containers/{id}/rename?name={encoded}"))
+            .await
+    }
+
+    pub async fn pause_container(&self, id: &str) -> Result<()> {
+        self.post(&format!("/containers/{id}/pause")).await
+    }
+
+    pub async fn unpause_container(&self, id: &str) -> Result<()> {
+        self.post(&format!("/containers/{id}/unpause")).await
+    }
+
+    /// Removes a container. Running containers are only removed when `force` is set,
+    /// in which case they're killed first.
+    pub async fn remove_container(&self, id: &str, force: bool) -> Result<()> {
+        let request = self
+            .request(Method::DELETE, &format!("/containers/{id}?force={force}"))
+            .body(AsyncBody::empty())?;
+        self.send(request).await?;
+        Ok(())
+    }
+
+    /// Removes all stopped containers.
+    pub async fn prune_containers(&self) -> Result<PruneReport> {
+        let request = self
+            .request(Method::POST, "/containers/prune")
+            .body(AsyncBody::empty())?;
+        let response: ContainerPruneResponse = self.read_json(request).await?;
+        Ok(PruneReport {
+            removed: response
+                .containers_deleted
+                .map_or(0, |deleted| deleted.len()),
+            space_reclaimed: response.space_reclaimed,
+        })
+    }
+
+    /// Removes the given containers that are stopped, like [`Self::prune_containers`]
+    /// restricted to a subset. Containers that fail to be removed are skipped.
+    pub async fn prune_containers_by_id(&self, ids: &[String]) -> Result<PruneReport> {
+        let filters = serde_json::json!({ "status": ["created", "exited", "dead"] }).to_string();
+        let filters: String = url::form_urlencoded::byte_serialize(filters.as_bytes()).collect();
+        let stopped: Vec<ContainerSummary> = self
+            .get_json(&format!(
+                "/containers/json?all=true&size=true&filters={filters}"
+            ))
+            .await?;
+
+        let mut report = PruneReport::default();
+        for container in stopped
+            .iter()
+            .filter(|container| ids.contains(&container.id))
+        {
+            match self.remove_container(&container.id, false).await {
+                Ok(()) => {
+                    report.removed += 1;
+                    report.space_reclaimed += container.size_rw.unwrap_or(0);
+                }
+                Err(error) => log::error!("failed to remove container {}: {error:?}", container.id),
+            }
+        }
+        Ok(report)
+    }
+
+    /// Creates a container, returning its id.
+    pub async fn create_container(&self, body: &CreateContainerRequest) -> Result<String> {
+        let response: IdResponse = self.post_json("/containers/create", body).await?;
+        Ok(response.id)
+    }
+
+    /// Starts pulling an image, streaming the progress of each of its layers.
+    pub async fn pull_image_with_progress(&self, reference: &str) -> Result<PullStream> {
+        let encoded: String = url::form_urlencoded::byte_serialize(reference.as_bytes()).collect();
+        let mut path = format!("/images/create?fromImage={encoded}");
+        // Without a tag, the engine pulls every tag of the repository.
+        if !has_tag_or_digest(reference) {
+            path.push_str("&tag=latest");
+        }
+        let request = self.request(Method::POST, &path).body(AsyncBody::empty())?;
+        let response = self.send(request).await?;
+        Ok(PullStream {
+            reference: reference.to_string(),
+            lines: JsonLines::new(response.into_body()),
+        })
+    }
+
+    /// Creates an image from the container's current state, like `docker commit`,
+    /// returning the new image's id.
+    pub async fn commit_container(&self, id: &str, reference: &str) -> Result<String> {
+        let (repo, tag) = split_reference(reference);
+        let repo: String = url::form_urlencoded::byte_serialize(repo.as_bytes()).collect();
+        let mut path = format!("/commit?container={id}&repo={repo}");
+        if let Some(tag) = tag {
+            let tag: String = url::form_urlencoded::byte_serialize(tag.as_bytes()).collect();
+            path.push_str(&format!("&tag={tag}"));
+        }
+        let request = self.request(Method::POST, &path).body(AsyncBody::empty())?;
+        let response: IdResponse = self.read_json(request).await?;
+        Ok(response.id)
+    }
+
+    /// Starts reading a regular file out of a container, like `docker cp`.
+    pub async fn copy_from_container(&self, id: &str, path: &str) -> Result<ContainerFile> {
+        let encoded: String = url::form_urlencoded::byte_serialize(path.as_bytes()).collect();
+        let request = self
+            .request(
+                Method::GET,
+                &format!("/containers/{id}/archive?path={encoded}"),
+            )
+            .body(AsyncBody::empty())?;
+        let response = self.send(request).await?;
+
+        let archive = Archive::new(response.into_body());
+        let mut entries = archive.entries()?;
+        let entry = entries
+            .next()
+            .await
+            .with_context(|| format!("{path} was not found"))??;
+        if !entry.header().entry_type().is_file() {
+            return Err(anyhow!("{path} is not a regular file"));
+        }
+        Ok(ContainerFile {
+            size: entry.header().size()?,
+            entry: Box::pin(entry),
+            bytes_read: Arc::default(),
+        })
+    }
+
+    /// Follows a container's stdout and stderr, starting with its most recent lines.
+    pub async fn container_logs(&self, id: &str) -> Result<LogStream> {
+        let path = format!(
+            "/containers/{id}/logs?follow=true&stdout=true&stderr=true&tail={}",
+            LOG_TAIL_LINES
+        );
+        let request = self.request(Method::GET, &path).body(AsyncBody::empty())?;
+        let response = self.send(request).await?;
+        Ok(LogStream {
+            body: response.into_body(),
+            decoder: LogDecoder::default(),
+        })
+    }
+
+    /// Runs `cmd` in a running container, streaming its stdout and stderr.
+    pub async fn exec(&self, id: &str, cmd: &[String]) -> Result<ExecStream> {
+        let body = CreateExecRequest {
+            cmd,
+            attach_stdout: true,
+            attach_stderr: true,
+        };
+        let IdResponse { id: exec_id } = self
+            .post_json(&format!("/containers/{id}/exec"), &body)
+            .await?;
+        let request = self
+            .request(Method::POST, &format!("/exec/{exec_id}/start"))
+            .header("Content-Type", "application/json")
+            .body(AsyncBody::from(serde_json::to_vec(&StartExecRequest {
+                detach: false,
+            })?))?;
+        let response = self.send(request).await?;
+        Ok(ExecStream {
+            exec_id,
+            output: LogStream {
+                body: response.into_body(),
+                decoder: LogDecoder::default(),
+            },
+        })
+    }
+
+    /// Runs `cmd` in a running container, discarding its output, and returns its
+    /// exit code.
+    pub async fn exec_status(&self, id: &str, cmd: &[String]) -> Result<Option<i64>> {
+        let mut exec = self.exec(id, cmd).await?;
+        while exec.next_lines().await?.is_some() {}
+        self.exec_exit_code(&exec.exec_id).await
+    }
+
+    /// The exit code of a command started by `exec`, or `None` while it's running.
+    pub async fn exec_exit_code(&self, exec_id: &str) -> Result<Option<i64>> {
+        let inspect: ExecInspect = self.get_json(&format!("/exec/{exec_id}/json")).await?;
+        Ok(if inspect.running {
+            None
+        } else {
+            inspect.exit_code
+        })
+    }
+
+    /// Subscribes to container lifecycle events as they happen.
+    pub async fn container_events(&self) -> Result<EventStream> {
+        let filters = serde_json::json!({ "type": ["container"], "event": CONTAINER_EVENTS });
+        let filters: String =
+            url::form_urlencoded::byte_serialize(filters.to_string().as_bytes()).collect();
+        let request = self
+            .request(Method::GET, &format!("/events?filters={filters}"))
+            .body(AsyncBody::empty())?;
+        let response = self.send(request).await?;
+        Ok(EventStream {
+            lines: JsonLines::new(response.into_body()),
+        })
+    }
+
+    pub async fn container_stats(&self, id: &str) -> Result<ContainerStatsResponse> {
+        self.get_json(&format!("/containers/{id}/stats?stream=false"))
+            .await
+    }
+
+    fn request(&self, method: Method, path: &str) -> Builder {
+        let mut builder = Request::builder()
+            .method(method)
+            .uri(format!("{}{}", self.base_url, path));
+        if let Some(dialer) = &self.dialer {
+            builder = builder.dial(dialer.clone());
+        }
+        builder
+    }
+
+    async fn send(&self, request: Request<AsyncBody>) -> Result<Response<AsyncBody>> {
+        let mut response = self
+            .http
+            .send_async(request)
+            .await
+            .context("failed to connect to the Docker daemon")?;
+        // Lifecycle endpoints answer `304 Not Modified` when the container is
+        // already in the requested state, which is not worth reporting.
+        if response.status().is_success() || response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(response);
+        }
+
+        let mut body = Vec::new();
+        response.body_mut().read_to_end(&mut body).await?;
+        let message = serde_json::from_slice::<ErrorResponse>(&body)
+            .map(|error| error.message)
+            .unwrap_or_else(|_| String::from_utf8_lossy(&body).into_owned());
+        Err(anyhow!(
+            "Docker request failed with {}: {}",
+            response.status(),
+            message.trim()
+        ))
+    }
+
+    async fn post(&self, path: &str) -> Result<()> {
+        let request = self.request(Method::POST, path).body(AsyncBody::empty())?;
+        self.send(request).await?;
+        Ok(())
+    }
+
+    async fn post_json<B: Serialize, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<T> {
+        let request = self
+            .request(Method::POST, path)
+            .header("Content-Type", "application/json")
+            .body(AsyncBody::from(serde_json::to_vec(body)?))?;
+        self.read_json(request).await
+    }
+
+    async fn get_json<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let request = self.request(Method::GET, path).body(AsyncBody::empty())?;
+        self.read_json(request).await
+    }
+
+    async fn read_json<T: DeserializeOwned>(&self, request: Request<AsyncBody>) -> Result<T> {
+        let mut response = self.send(request).await?;
+        let mut body = Vec::new();
+        response.body_mut().read_to_end(&mut body).await?;
+        Ok(serde_json::from_slice(&body)?)
+    }
+}
+
+/// Whether an image reference names a tag or digest, e.g. `rust:1.78` but not
+/// `localhost:5000/rust`.
+fn has_tag_or_digest(reference: &str) -> bool {
+    let name = reference.rsplit('/').next().unwrap_or(reference);
+    reference.contains('@') || name.contains(':')
+}
+
+/// Splits a reference such as `localhost:5000/app:v1` into its repository and tag.
+fn split_reference(reference: &str) -> (&str, Option<&str>) {
+    match reference.rsplit_once(':') {
+        Some((repo, tag)) if !tag.contains('/') => (repo, Some(tag)),
+        _ => (reference, None),
+    }
+}
+
+fn cli_command<S: AsRef<OsStr>>(
+    docker_host: &str,
+    args: &[S],
+    current_dir: Option<&Path>,
+) -> smol::process::Command {
+    let mut command = smol::process::Command::new("docker");
+    command.env("DOCKER_HOST", docker_host).args(args);
+    if let Some(current_dir) = current_dir {
+        command.current_dir(current_dir);
+    }
+    command
+}
+
+fn cli_subcommand<S: AsRef<OsStr>>(args: &[S]) -> String {
+    args.first()
+        .map(|arg| arg.as_ref().to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Runs the Docker CLI against `docker_host` and returns its standard output, for
+/// the few operations that are impractical to implement with the Engine API.
+pub async fn run_cli<S: AsRef<OsStr>>(
+    docker_host: &str,
+    args: &[S],
+    current_dir: Option<&Path>,
+) -> Result<String> {
+    let mut command = cli_command(docker_host, args, current_dir);
+    let subcommand = cli_subcommand(args);
+    let output = command
+        .output()
+        .await
+        .with_context(|| format!("failed to run `docker {subcommand}`"))?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "`docker {subcommand}` failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// A running Docker CLI command, whose standard output and error are read
+/// line by line as they're written. Dropping it kills the command.
+pub struct CliProcess {
+    subcommand: String,
+    child: smol::process::Child,
+    lines: Pin<Box<dyn Stream<Item = io::Result<String>> + Send>>,
+}
+
+impl CliProcess {
+    fn spawn<S: AsRef<OsStr>>(mut command: smol::process::Command, args: &[S]) -> Result<Self> {
+        let subcommand = cli_subcommand(args);
+        let mut child = command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .with_context(|| format!("failed to run `docker {subcommand}`"))?;
+        let stdout = child.stdout.take().context("no stdout")?;
+        let stderr = child.stderr.take().context("no stderr")?;
+        let lines = futures::stream::select(
+            BufReader::new(stdout).lines(),
+            BufReader::new(stderr).lines(),
+        );
+        Ok(Self {
+            subcommand,
+            child,
+            lines: Box::pin(lines),
+        })
+    }
+
+    /// Waits for the next line of output, returning `None` once both streams end.
+    pub async fn next_line(&mut self) -> Result<Option<String>> {
+        Ok(self.lines.next().await.transpose()?)
+    }
+
+    /// Waits for the command to exit, failing if it didn't succeed.
+    pub async fn wait(mut self) -> Result<()> {
+        let status = self.child.status().await?;
+        if !status.success() {
+            return Err(anyhow!("`docker {}` failed with {status}", self.subcommand));
+        }
+        Ok(())
+    }
+}
+
+/// Builds `dockerfile` with the Docker CLI, which takes care of sending `context`
+/// to the daemon, tagging the image as `tag`. Build arguments are `NAME=value`.
+pub fn build_image(
+    docker_host: &str,
+    dockerfile: &Path,
+    tag: &str,
+    context: &Path,
+    build_args: &[String],
+) -> Result<CliProcess> {
+    let mut args = vec![
+        OsStr::new("build"),
+        OsStr::new("--file"),
+        dockerfile.as_os_str(),
+        OsStr::new("--tag"),
+        OsStr::new(tag),
+    ];
+    for build_arg in build_args {
+        args.extend([OsStr::new("--build-arg"), OsStr::new(build_arg)]);
+    }
+    args.push(context.as_os_str());
+    let mut command = cli_command(docker_host, &args, None);
+    // BuildKit otherwise redraws its progress in place, which only works in a terminal.
+    command.env("BUILDKIT_PROGRESS", "plain");
+    CliProcess::spawn(command, &args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_decoder_multiplexed() {
+        fn frame(stream: u8, payload: &str) -> Vec<u8> {
+            let mut frame = vec![stream, 0, 0, 0];
+            frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+            frame.extend_from_slice(payload.as_bytes());
+            frame
+        }
+
+        let mut decoder = LogDecoder::default();
+        let bytes = [frame(1, "hello\nwor"), frame(2, "ld\r\n"), frame(1, "tail")].concat();
+
+        // Feed the frames in small chunks to exercise partial headers and payloads.
+        let mut lines = Vec::new();
+        for chunk in bytes.chunks(3) {
+            lines.extend(decoder.push(chunk));
+        }
+        assert_eq!(lines, ["hello", "world"]);
+        assert_eq!(decoder.finish().as_deref(), Some("tail"));
+        assert_eq!(decoder.finish(), None);
+    }
+
+    #[test]
+    fn test_log_decoder_raw() {
+        let mut decoder = LogDecoder::default();
+        assert_eq!(decoder.push(b"$ "), Vec::<String>::new());
+        assert_eq!(decoder.push(b"ls\r\nfoo bar\n"), ["$ ls", "foo bar"]);
+        assert_eq!(decoder.push(b"baz"), Vec::<String>::new());
+        assert_eq!(decoder.finish().as_deref(), Some("baz"));
+    }
+
+    #[test]
+    fn test_parse_container_event() {
+        let event = ContainerEvent::parse(
+            br#"{
+                "Type": "container",
+                "Action": "die",
+                "Actor": { "ID": "abc", "Attributes": { "exitCode": "137", "name": "web" } }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            event,
+            Some(ContainerEvent::Died {
+                id: "abc".into(),
+                name: "web".into(),
+                exit_code: 137
+            })
+        );
+
+        let event = ContainerEvent::parse(
+            br#"{"Type":"network","Action":"connect","Actor":{"ID":"def","Attributes":{}}}"#,
+        )
+        .unwrap();
+        assert_eq!(event, None);
+    }
+
+    #[test]
+    fn test_has_tag_or_digest() {
+        assert!(has_tag_or_digest("rust:1.78"));
+        assert!(has_tag_or_digest("localhost:5000/rust:1.78"));
+        assert!(has_tag_or_digest("rust@sha256:abcdef"));
+        assert!(!has_tag_or_digest("rust"));
+        assert!(!has_tag_or_digest("localhost:5000/rust"));
+    }
+
+    #[test]
+    fn test_split_reference() {
+        assert_eq!(split_reference("app:v1"), ("app", Some("v1")));
+        assert_eq!(
+            split_reference("localhost:5000/app:v1"),
+            ("localhost:5000/app", Some("v1"))
+        );
+        assert_eq!(
+            split_reference("localhost:5000/app"),
+            ("localhost:5000/app", None)
+        );
+        assert_eq!(split_reference("app"), ("app", None));
+    }
+
+    #[test]
+    fn test_health_status() {
+        assert_eq!(
+            HealthStatus::from_status("Up 5 minutes (healthy)"),
+            Some(HealthStatus::Healthy)
+        );
+        assert_eq!(
+            HealthStatus::from_status("Up 5 minutes (unhealthy)"),
+            Some(HealthStatus::Unhealthy)
+        );
+        assert_eq!(
+            HealthStatus::from_status("Up 2 seconds (health: starting)"),
+            Some(HealthStatus::Starting)
+        );
+        assert_eq!(HealthStatus::from_status("Up 5 minutes"), None);
+        assert_eq!(HealthStatus::from_status("Exited (0) 3 hours ago"), None);
+    }
+
+    #[test]
+    fn test_container_stats() {
+        let stats: ContainerStatsResponse = serde_json::from_str(
+            r#"{
+                "cpu_stats": {
+                    "cpu_usage": { "total_usage": 300 },
+                    "system_cpu_usage": 2000,
+                    "online_cpus": 4
+                },
+                "precpu_stats": {
+                    "cpu_usage": { "total_usage": 100 },
+                    "system_cpu_usage": 1000
+                },
+                "memory_stats": {
+                    "usage": 1000,
+                    "limit": 4000,
+                    "stats": { "inactive_file": 200 }
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(stats.cpu_percent(), 80.);
+        assert_eq!(stats.memory_usage(), 800);
+        assert_eq!(stats.memory_limit(), 4000);
+
+        // Paused or just-started containers report empty samples.
+        let stats: ContainerStatsResponse =
+            serde_json::from_str(r#"{ "cpu_stats": {}, "precpu_stats": {}, "memory_stats": {} }"#)
+                .unwrap();
+        assert_eq!(stats.cpu_percent(), 0.);
+        assert_eq!(stats.memory_usage(), 0);
+    }
+
+    #[test]
+    fn test_disk_usage() {
+        let response: SystemDfResponse = serde_json::from_str(
+            r#"{
+                "LayersSize": 1000,
+                "Images": [
+                    { "Id": "sha256:a", "Size": 600, "SharedSize": 100, "Containers": 1 },
+                    { "Id": "sha256:b", "Size": 400, "SharedSize": 100, "Containers": 0 }
+                ],
+                "Containers": [
+                    { "Id": "c1", "State": "running", "SizeRw": 10 },
+                    { "Id": "c2", "State": "exited", "SizeRw": 20 }
+                ],
+                "Volumes": [
+                    { "Name": "v1", "UsageData": { "Size": 50, "RefCount": 1 } },
+                    { "Name": "v2", "UsageData": { "Size": 70, "RefCount": 0 } },
+                    { "Name": "v3", "UsageData": { "Size": -1, "RefCount": -1 } }
+                ],
+                "BuildCache": []
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            DiskUsage::from(response),
+            DiskUsage {
+                images: 1000,
+                containers: 30,
+                volumes: 120,
+                reclaimable: 300 + 20 + 70,
+            }
+        );
+
+        // Older engines report `null` for empty lists.
+        let response: SystemDfResponse = serde_json::from_str(
+            r#"{ "LayersSize": 0, "Images": null, "Containers": null, "Volumes": null }"#,
+        )
+        .unwrap();
+        assert_eq!(DiskUsage::from(response), DiskUsage::default());
+    }
+}
@@ -0,0 +1,127 @@
+use anyhow::{Context, Result};
+use futures::StreamExt;
+use project::Fs;
+use serde::Deserialize;
+use std::path::PathBuf;
+use util::{paths, ResultExt};
+
+/// The context the Docker CLI uses when no other is selected. It isn't stored
+/// on disk, and talks to `DOCKER_HOST` or the platform's default daemon.
+pub const DEFAULT_CONTEXT: &str = "default";
+
+/// A Docker CLI context, naming a daemon to talk to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DockerContext {
+    pub name: String,
+    pub host: String,
+}
+
+/// A context's `meta.json`, as written by `docker context create`.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ContextMetadata {
+    name: String,
+    endpoints: Endpoints,
+}
+
+#[derive(Deserialize)]
+struct Endpoints {
+    docker: Option<Endpoint>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Endpoint {
+    host: String,
+}
+
+impl DockerContext {
+    fn parse(content: &str) -> Result<Option<Self>> {
+        let metadata: ContextMetadata = serde_json::from_str(content)?;
+        Ok(metadata.endpoints.docker.map(|endpoint| Self {
+            name: metadata.name,
+            host: endpoint.host,
+        }))
+    }
+}
+
+/// Where the Docker CLI keeps its configuration, honoring `DOCKER_CONFIG`.
+fn config_dir() -> PathBuf {
+    std::env::var_os("DOCKER_CONFIG")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| paths::HOME.join(".docker"))
+}
+
+/// Lists the default context, using `default_host`, followed by the contexts
+/// created with the Docker CLI, sorted by name.
+pub async fn load_contexts(fs: &dyn Fs, default_host: String) -> Result<Vec<DockerContext>> {
+    let mut contexts = Vec::new();
+    let meta_dir = config_dir().join("contexts").join("meta");
+    if fs.is_dir(&meta_dir).await {
+        let mut entries = fs.read_dir(&meta_dir).await?;
+        while let Some(entry) = entries.next().await {
+            let Some(entry) = entry.log_err() else {
+                continue;
+            };
+            let meta_path = entry.join("meta.json");
+            if !fs.is_file(&meta_path).await {
+                continue;
+            }
+            // Skip contexts that can't be read, rather than losing all of them.
+            let context = async {
+                let content = fs.load(&meta_path).await?;
+                DockerContext::parse(&content)
+            }
+            .await
+            .with_context(|| format!("failed to load {}", meta_path.display()))
+            .log_err()
+            .flatten();
+            if let Some(context) = context {
+                contexts.push(context);
+            }
+        }
+    }
+    contexts.sort_by(|a, b| a.name.cmp(&b.name));
+
+    contexts.insert(
+        0,
+        DockerContext {
+            name: DEFAULT_CONTEXT.into(),
+            host: default_host,
+        },
+    );
+    Ok(contexts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_context() {
+        let context = DockerContext::parse(
+            r#"{
+                "Name": "desktop-linux",
+                "Metadata": { "Description": "Docker Desktop" },
+                "Endpoints": {
+                    "docker": {
+                        "Host": "unix:///home/user/.docker/desktop/docker.sock",
+                        "SkipTLSVerify": false
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            context,
+            Some(DockerContext {
+                name: "desktop-linux".into(),
+                host: "unix:///home/user/.docker/desktop/docker.sock".into(),
+            })
+        );
+
+        // Contexts for other orchestrators have no Docker endpoint.
+        let context = DockerContext::parse(r#"{ "Name": "k8s", "Endpoints": {} }"#).unwrap();
+        assert_eq!(context, None);
+    }
+}
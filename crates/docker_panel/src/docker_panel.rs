@@ -0,0 +1,6225 @@
+mod compose;
+mod devcontainer;
+mod docker;
+mod docker_context;
+mod docker_panel_settings;
+mod port_forward;
+
+use anyhow::Result;
+use collections::{BTreeMap, HashMap, HashSet};
+use compose::{ComposeCommand, ComposeProject};
+use db::kvp::KEY_VALUE_STORE;
+use devcontainer::{
+    find_dev_container, DevContainer, LifecycleCommand, RunningContainer, LOCAL_FOLDER_LABEL,
+};
+pub use docker::ContainerState;
+use docker::{
+    ContainerEvent, ContainerStatsResponse, ContainerSummary, CreateContainerRequest, DiskUsage,
+    DockerClient, HealthStatus, ImageSummary, Mount, Port, PortProtocol, PruneReport, PullProgress,
+    VolumeSummary, DEFAULT_DOCKER_HOST,
+};
+use docker_context::{DockerContext, DEFAULT_CONTEXT};
+pub use docker_panel_settings::DockerPanelSettings;
+use editor::{Editor, EditorEvent};
+use futures::{channel::mpsc, StreamExt};
+use gpui::{
+    actions, anchored, deferred, list, percentage, Animation, AnimationExt, AnyElement,
+    AnyWindowHandle, AppContext, AsyncWindowContext, ClipboardItem, DismissEvent, EventEmitter,
+    FocusHandle, FocusableView, IntoElement, KeyContext, ListAlignment, ListOffset,
+    ListScrollEvent, ListState, Model, MouseButton, MouseDownEvent, ParentElement, Point,
+    PromptLevel, Render, Styled, Task, Transformation, View, ViewContext, VisualContext, WeakView,
+    WindowContext,
+};
+use project::{Fs, Project};
+use serde::{Deserialize, Serialize};
+use settings::{Settings, SettingsStore};
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    collections::VecDeque,
+    net::SocketAddr,
+    path::Path,
+    pin::Pin,
+    sync::{atomic, atomic::AtomicU64, Arc},
+    time::Duration,
+};
+use task::{RevealStrategy, SpawnInTerminal, TaskId};
+use terminal_view::terminal_panel::TerminalPanel;
+use theme::ThemeSettings;
+use time::{OffsetDateTime, UtcOffset};
+use ui::{popover_menu, prelude::*, Checkbox, ContextMenu, Indicator, Label, TintColor, Tooltip};
+use util::{paths, ResultExt};
+use workspace::{
+    dock::{DockPosition, Panel, PanelEvent},
+    notifications::NotificationId,
+    Toast, Workspace,
+};
+
+const DOCKER_PANEL_KEY: &str = "DockerPanel";
+/// The version of [`SerializedDockerPanel`] written by this build. Bump it when
+/// adding fields, which must have defaults so older blobs still load.
+const SERIALIZATION_VERSION: u32 = 1;
+const STATS_POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// How many lines of a container's logs are kept while following them, dropping
+/// the oldest, so a chatty container doesn't grow the buffer without bound.
+const MAX_LOG_LINES: usize = 10_000;
+/// Computing disk usage is expensive for the daemon, so it's polled less often
+/// than the container list.
+const DISK_USAGE_POLL_INTERVAL: Duration = Duration::from_secs(30);
+const TOAST_DURATION: Duration = Duration::from_secs(5);
+/// How long the logs' copy button confirms that the logs were copied.
+const COPIED_CONFIRMATION_DURATION: Duration = Duration::from_secs(2);
+const COPY_PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+/// How long the Docker host setting must stay unchanged before reconnecting, as
+/// some setups save the settings file on every keystroke.
+const RECONNECT_DEBOUNCE: Duration = Duration::from_millis(500);
+/// The group of containers that don't belong to a Compose project. Compose project
+/// names are lowercase, so it can't clash with one.
+const STANDALONE_GROUP: &str = "Standalone";
+
+pub struct DockerPanel {
+    fs: Arc<dyn Fs>,
+    docker: Option<Arc<DockerClient>>,
+    docker_contexts: Vec<DockerContext>,
+    /// The Docker CLI context to connect to, if not the default one.
+    docker_context: Option<String>,
+    load_docker_contexts_task: Task<()>,
+    /// Reconnects once the Docker host setting has stopped changing.
+    reconnect_task: Task<()>,
+    width: Option<Pixels>,
+    /// Mirrors the `dock` setting, which `set_position` writes to, so the setting
+    /// persists the position and is honored even when edited while Zed is closed.
+    position: DockPosition,
+    active: bool,
+    tab: PanelTab,
+    sort: SortBy,
+    containers: Vec<ContainerEntry>,
+    /// The rows of the container list, after filtering and grouping `containers`.
+    list_entries: Vec<ListEntry>,
+    container_list: ListState,
+    collapsed_groups: HashSet<String>,
+    dev_container: Option<DevContainer>,
+    detect_dev_container_task: Task<()>,
+    reopen_in_container_task: Option<Task<()>>,
+    rebuild: Option<RebuildDevContainer>,
+    dev_container_commands: Option<DevContainerCommands>,
+    /// The ports of the running dev container made available on the host, in
+    /// the order they're configured.
+    port_forwards: Vec<PortForward>,
+    /// The dev container `port_forwards` belong to.
+    port_forwards_container: Option<String>,
+    filter_editor: View<Editor>,
+    filter: String,
+    connection: ConnectionState,
+    last_connection_attempt: Option<OffsetDateTime>,
+    local_timezone: UtcOffset,
+    fetch_containers_task: Task<()>,
+    images: Vec<ImageEntry>,
+    fetch_images_task: Task<()>,
+    /// Running or removing images, keyed by [`ImageEntry::key`].
+    image_tasks: HashMap<String, Task<()>>,
+    pull_editor: View<Editor>,
+    /// The latest progress of the image being pulled, for each of its layers.
+    pull_progress: Vec<PullProgress>,
+    pull_task: Option<Task<()>>,
+    volumes: Vec<VolumeEntry>,
+    fetch_volumes_task: Task<()>,
+    /// Volumes being removed, keyed by name.
+    volume_tasks: HashMap<String, Task<()>>,
+    prune_volumes_task: Option<Task<()>>,
+    build: Option<BuildImage>,
+    view: PanelView,
+    /// The latest lines of the followed logs, up to [`MAX_LOG_LINES`].
+    log_lines: VecDeque<SharedString>,
+    log_list: ListState,
+    follow_logs: bool,
+    logs_task: Task<()>,
+    /// The pretty-printed `docker inspect` output of the inspected container, or
+    /// the error fetching it. Unset while it's being fetched.
+    inspect_output: Option<Result<SharedString, SharedString>>,
+    inspect_task: Task<()>,
+    /// Resets the logs' copy button once the confirmation has been shown.
+    logs_copied_task: Option<Task<()>>,
+    refreshing: bool,
+    refresh_task: Task<()>,
+    events_task: Task<()>,
+    stats_tasks: HashMap<String, Task<()>>,
+    lifecycle_tasks: HashMap<String, Task<()>>,
+    /// Running `docker compose` commands, keyed by project name.
+    compose_tasks: HashMap<String, Task<()>>,
+    /// The container whose row is asking to confirm its removal.
+    confirming_removal: Option<String>,
+    renaming: Option<Rename>,
+    copy_from_container: Option<CopyFromContainer>,
+    committing: Option<CommitContainer>,
+    /// The container highlighted for keyboard navigation. Unlike `selected`, this
+    /// is a single row, and isn't tied to select mode.
+    selected_container: Option<String>,
+    /// Whether rows can be selected for bulk actions.
+    selecting: bool,
+    selected: HashSet<String>,
+    confirming_bulk_removal: bool,
+    bulk_action_task: Option<Task<()>>,
+    prune_task: Option<Task<()>>,
+    disk_usage: Option<DiskUsage>,
+    disk_usage_task: Task<()>,
+    reclaim_task: Option<Task<()>>,
+    context_menu: Option<(View<ContextMenu>, Point<Pixels>, gpui::Subscription)>,
+    pending_serialization: Task<Option<()>>,
+    subscriptions: Vec<gpui::Subscription>,
+    workspace: WeakView<Workspace>,
+    toast_dismissal: Task<()>,
+    focus_handle: FocusHandle,
+}
+
+/// A container as displayed in the panel.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContainerEntry {
+    pub id: String,
+    pub name: String,
+    pub image: String,
+    pub created: OffsetDateTime,
+    pub state: ContainerState,
+    pub status: String,
+    /// Unset for containers without a health check.
+    pub health: Option<HealthStatus>,
+    /// The last line of output of the latest health check, if any.
+    pub health_check_output: Option<SharedString>,
+    /// The Docker Compose project the container belongs to, if any.
+    pub compose: Option<ComposeProject>,
+    pub ports: Vec<PublishedPort>,
+    pub mounts: Vec<Mount>,
+    /// The worktree root the container was created for, if it's a dev container.
+    pub dev_container_folder: Option<String>,
+    /// The latest resource usage sample, if one has been received.
+    pub stats: Option<ContainerStats>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ContainerStats {
+    pub cpu_percent: f32,
+    pub memory_usage: u64,
+    pub memory_limit: u64,
+}
+
+impl ContainerEntry {
+    pub fn is_running(&self) -> bool {
+        self.state == ContainerState::Running
+    }
+
+    /// Paused containers aren't running, and Docker reports no stats for them.
+    pub fn is_paused(&self) -> bool {
+        self.state == ContainerState::Paused
+    }
+
+    /// Whether the container has stopped, and would be removed by a prune.
+    pub fn is_stopped(&self) -> bool {
+        matches!(
+            self.state,
+            ContainerState::Created | ContainerState::Exited | ContainerState::Dead
+        )
+    }
+
+    /// Orders containers from the most to the least alive.
+    fn state_rank(&self) -> u8 {
+        match self.state {
+            ContainerState::Running => 0,
+            ContainerState::Restarting => 1,
+            ContainerState::Paused => 2,
+            ContainerState::Created => 3,
+            ContainerState::Removing => 4,
+            ContainerState::Exited => 5,
+            ContainerState::Dead => 6,
+            ContainerState::Unknown => 7,
+        }
+    }
+
+    /// Whether the container's name or image contains `query`, ignoring case.
+    /// `query` is expected to already be lowercase.
+    fn matches(&self, query: &str) -> bool {
+        self.name.to_lowercase().contains(query) || self.image.to_lowercase().contains(query)
+    }
+}
+
+/// A container port published on the host.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublishedPort {
+    pub host_port: u16,
+    pub container_port: u16,
+    pub protocol: PortProtocol,
+    /// Whether the port can be reached at `localhost`.
+    pub local: bool,
+}
+
+impl PublishedPort {
+    /// The address to open the port at. Only TCP ports reachable at `localhost`
+    /// are assumed to serve HTTP.
+    fn url(&self) -> Option<String> {
+        (self.local && self.protocol == PortProtocol::Tcp)
+            .then(|| format!("http://localhost:{}", self.host_port))
+    }
+
+    fn label(&self) -> String {
+        match self.protocol {
+            PortProtocol::Tcp => format!("{}→{}", self.host_port, self.container_port),
+            protocol => format!(
+                "{}→{}/{}",
+                self.host_port,
+                self.container_port,
+                protocol.as_str()
+            ),
+        }
+    }
+}
+
+/// Collects the ports published on the host, sorted by host port. The engine
+/// lists a port once for each address it's bound to, e.g. both `0.0.0.0` and
+/// `::`, so those are merged.
+fn published_ports(ports: &[Port]) -> Vec<PublishedPort> {
+    let mut published = Vec::<PublishedPort>::new();
+    for port in ports {
+        let Some(host_port) = port.public_port else {
+            continue;
+        };
+        let local = port.ip.as_deref().map_or(true, |ip| {
+            matches!(ip, "" | "0.0.0.0" | "127.0.0.1" | "::" | "::1")
+        });
+
+        if let Some(existing) = published.iter_mut().find(|existing| {
+            existing.host_port == host_port
+                && existing.container_port == port.private_port
+                && existing.protocol == port.protocol
+        }) {
+            existing.local |= local;
+        } else {
+            published.push(PublishedPort {
+                host_port,
+                container_port: port.private_port,
+                protocol: port.protocol,
+                local,
+            });
+        }
+    }
+    published.sort_by_key(|port| (port.host_port, port.protocol.as_str()));
+    published
+}
+
+/// An image as displayed in the panel, listed once for each of its references.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageEntry {
+    pub id: String,
+    /// The image's `repo:tag`, unset for untagged images.
+    pub reference: Option<String>,
+    pub size: u64,
+    pub created: OffsetDateTime,
+}
+
+impl ImageEntry {
+    /// How the image is referred to when talking to the daemon. Removing an image
+    /// by one of its references only untags it, like `docker rmi` does.
+    fn key(&self) -> &str {
+        self.reference.as_deref().unwrap_or(&self.id)
+    }
+
+    fn label(&self) -> String {
+        self.reference.clone().unwrap_or_else(|| {
+            let id = self.id.trim_start_matches("sha256:");
+            format!("<none> ({})", &id[..id.len().min(12)])
+        })
+    }
+}
+
+/// Lists each image once for each of its references, sorted by reference, with
+/// untagged images last.
+fn image_entries(images: Vec<ImageSummary>) -> Vec<ImageEntry> {
+    let mut entries = Vec::new();
+    for image in images {
+        let created = OffsetDateTime::from_unix_timestamp(image.created)
+            .unwrap_or(OffsetDateTime::UNIX_EPOCH);
+        let references = image
+            .repo_tags
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|reference| reference != "<none>:<none>")
+            .collect::<Vec<_>>();
+        if references.is_empty() {
+            entries.push(ImageEntry {
+                id: image.id,
+                reference: None,
+                size: image.size,
+                created,
+            });
+        } else {
+            entries.extend(references.into_iter().map(|reference| ImageEntry {
+                id: image.id.clone(),
+                reference: Some(reference),
+                size: image.size,
+                created,
+            }));
+        }
+    }
+    entries.sort_by(|a, b| {
+        (a.reference.is_none(), &a.reference, a.created).cmp(&(
+            b.reference.is_none(),
+            &b.reference,
+            b.created,
+        ))
+    });
+    entries
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VolumeEntry {
+    pub name: String,
+    pub driver: String,
+    pub mountpoint: String,
+}
+
+impl From<VolumeSummary> for VolumeEntry {
+    fn from(summary: VolumeSummary) -> Self {
+        Self {
+            name: summary.name,
+            driver: summary.driver,
+            mountpoint: summary.mountpoint,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ContainerAction {
+    Start,
+    Stop,
+    Restart,
+    Pause,
+    Unpause,
+    Kill { signal: Signal },
+    Remove { force: bool },
+}
+
+/// The signals offered when killing a container.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Signal {
+    Term,
+    #[default]
+    Kill,
+    Int,
+    Hup,
+}
+
+impl Signal {
+    const ALL: [Signal; 4] = [Signal::Term, Signal::Kill, Signal::Int, Signal::Hup];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Signal::Term => "SIGTERM",
+            Signal::Kill => "SIGKILL",
+            Signal::Int => "SIGINT",
+            Signal::Hup => "SIGHUP",
+        }
+    }
+}
+
+impl ContainerAction {
+    fn verb(&self) -> &'static str {
+        match self {
+            ContainerAction::Start => "start",
+            ContainerAction::Stop => "stop",
+            ContainerAction::Restart => "restart",
+            ContainerAction::Pause => "pause",
+            ContainerAction::Unpause => "unpause",
+            ContainerAction::Kill { .. } => "kill",
+            ContainerAction::Remove { .. } => "remove",
+        }
+    }
+
+    fn past_tense(&self) -> &'static str {
+        match self {
+            ContainerAction::Start => "started",
+            ContainerAction::Stop => "stopped",
+            ContainerAction::Restart => "restarted",
+            ContainerAction::Pause => "paused",
+            ContainerAction::Unpause => "unpaused",
+            ContainerAction::Kill { .. } => "killed",
+            ContainerAction::Remove { .. } => "removed",
+        }
+    }
+
+    async fn perform(self, docker: &DockerClient, container_id: &str) -> Result<()> {
+        match self {
+            ContainerAction::Start => docker.start_container(container_id).await,
+            ContainerAction::Stop => docker.stop_container(container_id).await,
+            ContainerAction::Restart => docker.restart_container(container_id).await,
+            ContainerAction::Pause => docker.pause_container(container_id).await,
+            ContainerAction::Unpause => docker.unpause_container(container_id).await,
+            ContainerAction::Kill { signal } => {
+                docker.kill_container(container_id, signal.name()).await
+            }
+            ContainerAction::Remove { force } => docker.remove_container(container_id, force).await,
+        }
+    }
+}
+
+/// A container whose name is being edited in place.
+struct Rename {
+    container_id: String,
+    editor: View<Editor>,
+    /// Why the entered name was rejected, shown until it's edited.
+    error: Option<SharedString>,
+    _subscription: gpui::Subscription,
+}
+
+/// A file being copied out of a container, from entering its path until it's
+/// written to the host.
+struct CopyFromContainer {
+    container_id: String,
+    path_editor: View<Editor>,
+    /// The bytes copied so far and the file's size, once the copy has started.
+    progress: Option<(Arc<AtomicU64>, u64)>,
+    task: Option<Task<()>>,
+    /// Re-renders the progress while the file is being written.
+    progress_task: Task<()>,
+}
+
+/// A container being snapshotted to a new image, from entering the image's
+/// reference until it's created.
+struct CommitContainer {
+    container_id: String,
+    reference_editor: View<Editor>,
+    task: Option<Task<()>>,
+}
+
+/// A Dockerfile found in one of the workspace's worktrees.
+#[derive(Clone, Debug, PartialEq)]
+struct Dockerfile {
+    worktree_root: Arc<Path>,
+    /// Relative to `worktree_root`.
+    path: Arc<Path>,
+}
+
+impl Dockerfile {
+    /// The Dockerfile's path, prefixed with its worktree's name.
+    fn label(&self) -> String {
+        let worktree_name = self
+            .worktree_root
+            .file_name()
+            .map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+        Path::new(&worktree_name)
+            .join(&self.path)
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+/// An image being built from a Dockerfile, from picking the Dockerfile until the
+/// build's output is dismissed.
+struct BuildImage {
+    dockerfiles: Vec<Dockerfile>,
+    selected: usize,
+    tag_editor: View<Editor>,
+    /// The build context, relative to the Dockerfile's worktree root.
+    context_editor: View<Editor>,
+    output: Vec<SharedString>,
+    output_list: ListState,
+    task: Option<Task<()>>,
+}
+
+/// A port of the running dev container made available on the host.
+struct PortForward {
+    container_port: u16,
+    /// Unset until the forward is listening, and once it's removed.
+    host_port: Option<u16>,
+    /// Whether the container publishes the port itself, as with `appPort`, so
+    /// there's nothing to relay or remove.
+    published: bool,
+    /// Relays connections to the container.
+    task: Option<Task<()>>,
+    error: Option<SharedString>,
+}
+
+impl PortForward {
+    fn is_removed(&self) -> bool {
+        !self.published && self.task.is_none() && self.error.is_none()
+    }
+}
+
+/// The dev container being torn down and rebuilt, until its output is dismissed.
+struct RebuildDevContainer {
+    name: String,
+    output: Vec<SharedString>,
+    output_list: ListState,
+    task: Option<Task<()>>,
+}
+
+/// The lifecycle commands run in a dev container once it starts, until their
+/// output is dismissed.
+struct DevContainerCommands {
+    name: String,
+    commands: Vec<DevContainerCommand>,
+    output: Vec<SharedString>,
+    output_list: ListState,
+    task: Option<Task<()>>,
+}
+
+struct DevContainerCommand {
+    /// The `devcontainer.json` property the command came from, e.g. `postCreateCommand`.
+    property: &'static str,
+    command: LifecycleCommand,
+    status: CommandStatus,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CommandStatus {
+    Pending,
+    Running,
+    Succeeded,
+    Failed,
+}
+
+/// Whether a file is named like a Dockerfile, e.g. `Dockerfile`, `Dockerfile.dev`
+/// or `app.Dockerfile`.
+fn is_dockerfile(file_name: &str) -> bool {
+    file_name == "Dockerfile"
+        || file_name.starts_with("Dockerfile.")
+        || file_name.ends_with(".Dockerfile")
+        || file_name.ends_with(".dockerfile")
+}
+
+/// Whether Docker accepts `name` as a container name.
+fn is_valid_container_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphanumeric())
+        && !chars.as_str().is_empty()
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
+
+/// A row of the container list.
+#[derive(Clone, Debug, PartialEq)]
+enum ListEntry {
+    GroupHeader {
+        group: String,
+        project: Option<ComposeProject>,
+        count: usize,
+    },
+    Container {
+        container_id: String,
+    },
+}
+
+impl ListEntry {
+    /// Identifies the row across updates, regardless of its contents.
+    fn key(&self) -> &str {
+        match self {
+            ListEntry::GroupHeader { group, .. } => group,
+            ListEntry::Container { container_id } => container_id,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+enum SortKey {
+    Name,
+    Status,
+    Cpu,
+    Created,
+}
+
+impl SortKey {
+    const ALL: [SortKey; 4] = [
+        SortKey::Name,
+        SortKey::Status,
+        SortKey::Cpu,
+        SortKey::Created,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            SortKey::Name => "Name",
+            SortKey::Status => "Status",
+            SortKey::Cpu => "CPU",
+            SortKey::Created => "Created",
+        }
+    }
+
+    /// Numbers and dates are most useful largest first.
+    fn descending_by_default(&self) -> bool {
+        matches!(self, SortKey::Cpu | SortKey::Created)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct SortBy {
+    key: SortKey,
+    descending: bool,
+}
+
+impl Default for SortBy {
+    /// Newest first, the order the daemon lists containers in.
+    fn default() -> Self {
+        Self {
+            key: SortKey::Created,
+            descending: true,
+        }
+    }
+}
+
+impl SortBy {
+    /// Compares containers by the sort key, then by name. Containers without
+    /// stats sort last by CPU usage in either direction.
+    fn compare(&self, a: &ContainerEntry, b: &ContainerEntry) -> Ordering {
+        let directed = |ordering: Ordering| {
+            if self.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        };
+        match self.key {
+            SortKey::Name => directed(a.name.to_lowercase().cmp(&b.name.to_lowercase())),
+            SortKey::Status => directed(a.state_rank().cmp(&b.state_rank())),
+            SortKey::Cpu => match (a.stats, b.stats) {
+                (Some(a), Some(b)) => directed(a.cpu_percent.total_cmp(&b.cpu_percent)),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            },
+            SortKey::Created => directed(a.created.cmp(&b.created)),
+        }
+        .then_with(|| a.name.cmp(&b.name))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+enum PanelTab {
+    #[default]
+    Containers,
+    Images,
+    Volumes,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum PanelView {
+    List,
+    Logs { container_id: String },
+    Inspect { container_id: String },
+    Build,
+    RebuildDevContainer,
+    DevContainerCommands,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum ConnectionState {
+    Connecting,
+    Connected,
+    Disconnected { error: SharedString },
+}
+
+/// Identifies the toasts shown by the panel, so a new one replaces the last.
+struct DockerToast;
+
+/// A toast with a warning icon.
+struct WarningNotification {
+    message: SharedString,
+}
+
+impl EventEmitter<DismissEvent> for WarningNotification {}
+
+impl Render for WarningNotification {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        h_flex()
+            .elevation_3(cx)
+            .p_4()
+            .gap_2()
+            .child(
+                Icon::new(IconName::ExclamationTriangle)
+                    .size(IconSize::Small)
+                    .color(Color::Warning),
+            )
+            .child(div().max_w_80().child(Label::new(self.message.clone())))
+            .child(
+                IconButton::new("dismiss", IconName::Close)
+                    .icon_size(IconSize::Small)
+                    .on_click(cx.listener(|_, _, cx| cx.emit(DismissEvent))),
+            )
+    }
+}
+
+impl From<ContainerStatsResponse> for ContainerStats {
+    fn from(response: ContainerStatsResponse) -> Self {
+        Self {
+            cpu_percent: response.cpu_percent(),
+            memory_usage: response.memory_usage(),
+            memory_limit: response.memory_limit(),
+        }
+    }
+}
+
+impl From<ContainerSummary> for ContainerEntry {
+    fn from(summary: ContainerSummary) -> Self {
+        // The engine reports names with a leading slash, e.g. `/web-1`.
+        let name = summary
+            .names
+            .first()
+            .map(|name| name.trim_start_matches('/').to_string())
+            .unwrap_or_else(|| summary.id.chars().take(12).collect());
+        Self {
+            id: summary.id,
+            name,
+            image: summary.image,
+            created: OffsetDateTime::from_unix_timestamp(summary.created)
+                .unwrap_or(OffsetDateTime::UNIX_EPOCH),
+            state: summary.state,
+            health: HealthStatus::from_status(&summary.status),
+            health_check_output: None,
+            status: summary.status,
+            compose: ComposeProject::from_labels(&summary.labels),
+            ports: published_ports(&summary.ports),
+            mounts: summary.mounts,
+            dev_container_folder: summary.labels.get(LOCAL_FOLDER_LABEL).cloned(),
+            stats: None,
+        }
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct SerializedDockerPanel {
+    /// Missing, and so 0, in blobs written before the panel was versioned.
+    version: u32,
+    width: Option<Pixels>,
+    /// Still written, for older versions, but not restored since the `dock`
+    /// setting is authoritative.
+    position: Option<DockPosition>,
+    tab: Option<PanelTab>,
+    sort: Option<SortBy>,
+    docker_context: Option<String>,
+    filter: String,
+}
+
+#[derive(Debug)]
+pub enum Event {
+    DockPositionChanged,
+    Focus,
+    Dismissed,
+}
+
+actions!(
+    docker_panel,
+    [
+        ToggleFocus,
+        SelectNext,
+        SelectPrev,
+        OpenLogs,
+        StopSelected,
+        RestartSelected
+    ]
+);
+
+pub fn init(cx: &mut AppContext) {
+    DockerPanelSettings::register(cx);
+
+    cx.observe_new_views(|workspace: &mut Workspace, _| {
+        workspace.register_action(|workspace, _: &ToggleFocus, cx| {
+            workspace.toggle_panel_focus::<DockerPanel>(cx);
+        });
+    })
+    .detach();
+}
+
+impl DockerPanel {
+    pub fn new(workspace: &mut Workspace, cx: &mut ViewContext<Workspace>) -> View<Self> {
+        let fs = workspace.app_state().fs.clone();
+        let project = workspace.project().clone();
+        let workspace_handle = workspace.weak_handle();
+
+        cx.new_view(|cx: &mut ViewContext<Self>| {
+            let view = cx.view().downgrade();
+            let log_list = ListState::new(0, ListAlignment::Bottom, px(1000.), move |ix, cx| {
+                view.upgrade()
+                    .and_then(|view| view.update(cx, |this, cx| this.render_log_line(ix, cx)))
+                    .unwrap_or_else(|| div().into_any())
+            });
+            // Like `tail -f`: scrolling up stops following, scrolling back to the bottom resumes.
+            log_list.set_scroll_handler(cx.listener(|this, event: &ListScrollEvent, cx| {
+                let follow_logs = !event.is_scrolled;
+                if this.follow_logs != follow_logs {
+                    this.follow_logs = follow_logs;
+                    cx.notify();
+                }
+            }));
+
+            let view = cx.view().downgrade();
+            let container_list = ListState::new(0, ListAlignment::Top, px(1000.), move |ix, cx| {
+                view.upgrade()
+                    .and_then(|view| view.update(cx, |this, cx| this.render_list_entry(ix, cx)))
+                    .unwrap_or_else(|| div().into_any())
+            });
+
+            let filter_editor = cx.new_view(|cx| {
+                let mut editor = Editor::single_line(cx);
+                editor.set_placeholder_text("Filter containers…", cx);
+                editor
+            });
+            let filter_subscription =
+                cx.subscribe(&filter_editor, |this: &mut Self, editor, event, cx| {
+                    if let EditorEvent::BufferEdited = event {
+                        this.filter = editor.read(cx).text(cx);
+                        this.update_entries(cx);
+                        this.serialize(cx);
+                    }
+                });
+
+            let pull_editor = cx.new_view(|cx| {
+                let mut editor = Editor::single_line(cx);
+                editor.set_placeholder_text("Image to pull, e.g. rust:1.78", cx);
+                editor
+            });
+
+            let mut this = Self {
+                fs,
+                docker: None,
+                docker_contexts: Vec::new(),
+                docker_context: None,
+                load_docker_contexts_task: Task::ready(()),
+                reconnect_task: Task::ready(()),
+                width: None,
+                position: DockerPanelSettings::get_global(cx).dock,
+                active: false,
+                tab: PanelTab::default(),
+                sort: SortBy::default(),
+                containers: Vec::new(),
+                list_entries: Vec::new(),
+                container_list,
+                collapsed_groups: HashSet::default(),
+                dev_container: None,
+                detect_dev_container_task: Task::ready(()),
+                reopen_in_container_task: None,
+                rebuild: None,
+                dev_container_commands: None,
+                port_forwards: Vec::new(),
+                port_forwards_container: None,
+                filter_editor,
+                filter: String::new(),
+                connection: ConnectionState::Connecting,
+                last_connection_attempt: None,
+                local_timezone: cx.local_timezone(),
+                fetch_containers_task: Task::ready(()),
+                images: Vec::new(),
+                fetch_images_task: Task::ready(()),
+                image_tasks: HashMap::default(),
+                pull_editor,
+                pull_progress: Vec::new(),
+                pull_task: None,
+                volumes: Vec::new(),
+                fetch_volumes_task: Task::ready(()),
+                volume_tasks: HashMap::default(),
+                prune_volumes_task: None,
+                build: None,
+                view: PanelView::List,
+                log_lines: VecDeque::new(),
+                log_list,
+                follow_logs: true,
+                logs_task: Task::ready(()),
+                inspect_output: None,
+                inspect_task: Task::ready(()),
+                logs_copied_task: None,
+                refreshing: false,
+                refresh_task: Task::ready(()),
+                events_task: Task::ready(()),
+                stats_tasks: HashMap::default(),
+                lifecycle_tasks: HashMap::default(),
+                compose_tasks: HashMap::default(),
+                confirming_removal: None,
+                renaming: None,
+                copy_from_container: None,
+                committing: None,
+                selected_container: None,
+                selecting: false,
+                selected: HashSet::default(),
+                confirming_bulk_removal: false,
+                bulk_action_task: None,
+                prune_task: None,
+                disk_usage: None,
+                disk_usage_task: Task::ready(()),
+                reclaim_task: None,
+                context_menu: None,
+                pending_serialization: Task::ready(None),
+                subscriptions: vec![filter_subscription],
+                workspace: workspace_handle,
+                toast_dismissal: Task::ready(()),
+                focus_handle: cx.focus_handle(),
+            };
+
+            this.connect(cx);
+            this.load_docker_contexts(cx);
+            this.detect_dev_container(cx);
+            this.subscriptions.push(cx.on_release(Self::release));
+            this.subscriptions.push(cx.subscribe(
+                &project,
+                |this: &mut Self, _, event: &project::Event, cx| {
+                    match event {
+                        project::Event::WorktreeAdded | project::Event::WorktreeRemoved(_) => {
+                            this.detect_dev_container(cx)
+                        }
+                        project::Event::WorktreeUpdatedEntries(_, entries) => {
+                            if entries
+                                .iter()
+                                .any(|(path, _, _)| devcontainer::is_config_path(path))
+                            {
+                                this.detect_dev_container(cx)
+                            }
+                        }
+                        _ => {}
+                    }
+                },
+            ));
+
+            let mut old_dock_setting = DockerPanelSettings::get_global(cx).dock;
+            let mut old_refresh_interval = DockerPanelSettings::get_global(cx).refresh_interval;
+            let mut old_docker_host = docker_host(cx);
+            this.subscriptions.push(cx.observe_global::<SettingsStore>(
+                move |this: &mut Self, cx| {
+                    let new_dock_setting = DockerPanelSettings::get_global(cx).dock;
+                    if new_dock_setting != old_dock_setting {
+                        old_dock_setting = new_dock_setting;
+                        if this.position != new_dock_setting {
+                            this.position = new_dock_setting;
+                            this.serialize(cx);
+                            cx.emit(Event::DockPositionChanged);
+                        }
+                    }
+
+                    let new_refresh_interval = DockerPanelSettings::get_global(cx).refresh_interval;
+                    if new_refresh_interval != old_refresh_interval {
+                        old_refresh_interval = new_refresh_interval;
+                        if this.active {
+                            this.schedule_refresh(cx);
+                        }
+                    }
+
+                    let new_docker_host = docker_host(cx);
+                    if new_docker_host != old_docker_host {
+                        old_docker_host = new_docker_host;
+                        this.schedule_reconnect(cx);
+                    }
+                    cx.notify();
+                },
+            ));
+            this
+        })
+    }
+
+    pub fn load(
+        workspace: WeakView<Workspace>,
+        cx: AsyncWindowContext,
+    ) -> Task<Result<View<Self>>> {
+        cx.spawn(|mut cx| async move {
+            let serialized_panel = if let Some(panel) = cx
+                .background_executor()
+                .spawn(async move { KEY_VALUE_STORE.read_kvp(DOCKER_PANEL_KEY) })
+                .await
+                .log_err()
+                .flatten()
+            {
+                Some(serde_json::from_str::<SerializedDockerPanel>(&panel)?)
+            } else {
+                None
+            };
+
+            workspace.update(&mut cx, |workspace, cx| {
+                let panel = Self::new(workspace, cx);
+                if let Some(serialized_panel) = serialized_panel {
+                    panel.update(cx, |panel, cx| {
+                        panel.width = serialized_panel.width.map(|w| w.round());
+                        if let Some(tab) = serialized_panel.tab {
+                            panel.set_tab(tab, cx);
+                        }
+                        if let Some(sort) = serialized_panel.sort {
+                            panel.sort = sort;
+                            panel.update_entries(cx);
+                        }
+                        // Connects once the contexts have been loaded.
+                        panel.docker_context = serialized_panel.docker_context;
+                        if !serialized_panel.filter.is_empty() {
+                            panel.filter_editor.update(cx, |editor, cx| {
+                                editor.set_text(serialized_panel.filter, cx)
+                            });
+                        }
+                        cx.notify();
+                    });
+                }
+                panel
+            })
+        })
+    }
+
+    fn serialize(&mut self, cx: &mut ViewContext<Self>) {
+        let width = self.width;
+        let position = Some(self.position);
+        let tab = Some(self.tab);
+        let sort = Some(self.sort);
+        let docker_context = self.docker_context.clone();
+        let filter = self.filter.clone();
+        self.pending_serialization = cx.background_executor().spawn(
+            async move {
+                KEY_VALUE_STORE
+                    .write_kvp(
+                        DOCKER_PANEL_KEY.into(),
+                        serde_json::to_string(&SerializedDockerPanel {
+                            version: SERIALIZATION_VERSION,
+                            width,
+                            position,
+                            tab,
+                            sort,
+                            docker_context,
+                            filter,
+                        })?,
+                    )
+                    .await?;
+                anyhow::Ok(())
+            }
+            .log_err(),
+        );
+    }
+
+    /// (Re)creates the Docker client for the configured host, discarding any
+    /// state that belonged to the previous connection.
+    fn connect(&mut self, cx: &mut ViewContext<Self>) {
+        let host = self.active_docker_host(cx);
+        self.containers.clear();
+        self.update_entries(cx);
+        self.stats_tasks.clear();
+        self.lifecycle_tasks.clear();
+        self.confirming_removal = None;
+        self.renaming = None;
+        self.copy_from_container = None;
+        self.committing = None;
+        self.selected.clear();
+        self.confirming_bulk_removal = false;
+        self.bulk_action_task = None;
+        self.prune_task = None;
+        self.fetch_containers_task = Task::ready(());
+        self.images.clear();
+        self.image_tasks.clear();
+        self.fetch_images_task = Task::ready(());
+        self.pull_progress.clear();
+        self.pull_task = None;
+        self.volumes.clear();
+        self.volume_tasks.clear();
+        self.fetch_volumes_task = Task::ready(());
+        self.prune_volumes_task = None;
+        self.port_forwards.clear();
+        self.port_forwards_container = None;
+        self.disk_usage = None;
+        self.disk_usage_task = Task::ready(());
+        self.reclaim_task = None;
+        self.dev_container_commands = None;
+        self.refreshing = false;
+        self.close_detail_view(cx);
+
+        match DockerClient::new(&host) {
+            Ok(docker) => {
+                self.docker = Some(Arc::new(docker));
+                self.set_connection(ConnectionState::Connecting, cx);
+                // Fetch even while the panel is closed, so the dock button's badge is populated.
+                self.fetch_containers(cx);
+                self.fetch_tab(cx);
+                if self.active {
+                    self.subscribe_to_events(cx);
+                    self.poll_disk_usage(cx);
+                }
+            }
+            Err(error) => {
+                self.docker = None;
+                self.events_task = Task::ready(());
+                self.last_connection_attempt = Some(OffsetDateTime::now_utc());
+                self.set_connection(
+                    ConnectionState::Disconnected {
+                        error: error.to_string().into(),
+                    },
+                    cx,
+                );
+            }
+        }
+        cx.notify();
+    }
+
+    /// Reconnects after [`RECONNECT_DEBOUNCE`], unless called again in the meantime.
+    fn schedule_reconnect(&mut self, cx: &mut ViewContext<Self>) {
+        self.reconnect_task = cx.spawn(|this, mut cx| async move {
+            cx.background_executor().timer(RECONNECT_DEBOUNCE).await;
+            this.update(&mut cx, |this, cx| {
+                this.connect(cx);
+                this.load_docker_contexts(cx);
+            })
+            .ok();
+        });
+    }
+
+    /// The host of the selected Docker context, or the configured host when the
+    /// default context is selected or the selected one no longer exists.
+    fn active_docker_host(&self, cx: &AppContext) -> String {
+        self.docker_context
+            .as_ref()
+            .and_then(|name| {
+                self.docker_contexts
+                    .iter()
+                    .find(|context| &context.name == name)
+            })
+            .map_or_else(|| docker_host(cx), |context| context.host.clone())
+    }
+
+    /// Reads the Docker CLI's contexts, reconnecting if that changes the selected
+    /// context's host.
+    fn load_docker_contexts(&mut self, cx: &mut ViewContext<Self>) {
+        let fs = self.fs.clone();
+        let default_host = docker_host(cx);
+        self.load_docker_contexts_task = cx.spawn(|this, mut cx| async move {
+            let contexts = docker_context::load_contexts(fs.as_ref(), default_host.clone())
+                .await
+                .log_err()
+                .unwrap_or_else(|| {
+                    vec![DockerContext {
+                        name: DEFAULT_CONTEXT.into(),
+                        host: default_host,
+                    }]
+                });
+            this.update(&mut cx, |this, cx| {
+                let old_host = this.active_docker_host(cx);
+                this.docker_contexts = contexts;
+                if this.active_docker_host(cx) != old_host {
+                    this.connect(cx);
+                }
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    fn set_docker_context(&mut self, name: String, cx: &mut ViewContext<Self>) {
+        let docker_context = (name != DEFAULT_CONTEXT).then_some(name);
+        if self.docker_context == docker_context {
+            return;
+        }
+
+        self.docker_context = docker_context;
+        self.serialize(cx);
+        self.connect(cx);
+    }
+
+    fn detect_dev_container(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(workspace) = self.workspace.upgrade() else {
+            return;
+        };
+        let worktree_roots = workspace_roots(workspace.read(cx).project(), cx);
+        let fs = self.fs.clone();
+        self.detect_dev_container_task = cx.spawn(|this, mut cx| async move {
+            let dev_container = find_dev_container(fs.as_ref(), &worktree_roots)
+                .await
+                .log_err()
+                .flatten();
+            this.update(&mut cx, |this, cx| {
+                if this.dev_container != dev_container {
+                    this.dev_container = dev_container;
+                    this.update_port_forwards(cx);
+                    cx.notify();
+                }
+            })
+            .ok();
+        });
+    }
+
+    fn reopen_in_container(&mut self, cx: &mut ViewContext<Self>) {
+        let (Some(docker), Some(dev_container)) = (self.docker.clone(), self.dev_container.clone())
+        else {
+            return;
+        };
+        if self.reopen_in_container_task.is_some()
+            || self.is_rebuilding_dev_container()
+            || self.running_dev_container().is_some()
+        {
+            return;
+        }
+
+        let docker_host = self.active_docker_host(cx);
+        self.reopen_in_container_task = Some(cx.spawn(|this, mut cx| async move {
+            let result = cx
+                .background_executor()
+                .spawn({
+                    let dev_container = dev_container.clone();
+                    async move { dev_container.run(&docker, &docker_host).await }
+                })
+                .await;
+            this.update(&mut cx, |this, cx| {
+                this.reopen_in_container_task = None;
+                match result {
+                    Ok(container) => {
+                        this.selected_container = Some(container.id().to_string());
+                        match container {
+                            RunningContainer::Existing(_) => {}
+                            RunningContainer::Started(id) => {
+                                this.run_dev_container_commands(&dev_container, id, false, cx)
+                            }
+                            RunningContainer::Created(id) => {
+                                this.run_dev_container_commands(&dev_container, id, true, cx)
+                            }
+                        }
+                    }
+                    Err(error) => {
+                        let message = format!(
+                            "Failed to start dev container {}: {error}",
+                            dev_container.name()
+                        );
+                        this.show_toast(message, cx);
+                    }
+                }
+                this.fetch_containers(cx);
+                cx.notify();
+            })
+            .ok();
+        }));
+        cx.notify();
+    }
+
+    /// The running container created for the detected dev container, if any.
+    fn running_dev_container(&self) -> Option<&ContainerEntry> {
+        let local_folder = self.dev_container.as_ref()?.local_folder.to_string_lossy();
+        self.containers.iter().find(|container| {
+            container.is_running()
+                && container.dev_container_folder.as_deref() == Some(local_folder.as_ref())
+        })
+    }
+
+    /// Forwards the ports the dev container configures once it's running, and
+    /// stops forwarding them once it isn't.
+    fn update_port_forwards(&mut self, cx: &mut ViewContext<Self>) {
+        let running = self.running_dev_container().map(|container| {
+            let published = container
+                .ports
+                .iter()
+                .filter(|port| port.local && port.protocol == PortProtocol::Tcp)
+                .map(|port| (port.container_port, port.host_port))
+                .collect::<Vec<_>>();
+            (container.id.clone(), published)
+        });
+        let container_id = running.as_ref().map(|(id, _)| id.clone());
+        if self.port_forwards_container != container_id {
+            self.port_forwards_container = container_id;
+            self.port_forwards.clear();
+            cx.notify();
+        }
+        let (Some((_, published)), Some(dev_container)) = (running, &self.dev_container) else {
+            return;
+        };
+
+        let ports = dev_container.config.ports_to_forward();
+        let mut port_forwards = std::mem::take(&mut self.port_forwards);
+        let mut ports_to_forward = Vec::new();
+        for port in ports {
+            let existing = port_forwards
+                .iter()
+                .position(|forward| forward.container_port == port)
+                .map(|ix| port_forwards.remove(ix));
+            let forward = existing.unwrap_or_else(|| {
+                let host_port = published
+                    .iter()
+                    .find(|(container_port, _)| *container_port == port)
+                    .map(|(_, host_port)| *host_port);
+                if host_port.is_none() {
+                    ports_to_forward.push(port);
+                }
+                PortForward {
+                    container_port: port,
+                    host_port,
+                    published: host_port.is_some(),
+                    task: None,
+                    error: None,
+                }
+            });
+            self.port_forwards.push(forward);
+        }
+        for port in ports_to_forward {
+            self.forward_port(port, cx);
+        }
+        cx.notify();
+    }
+
+    /// Relays connections to the port from `localhost` to the container's
+    /// network address, failing if the host can't reach it.
+    fn forward_port(&mut self, container_port: u16, cx: &mut ViewContext<Self>) {
+        let (Some(docker), Some(container_id)) =
+            (self.docker.clone(), self.port_forwards_container.clone())
+        else {
+            return;
+        };
+        let Some(forward) = self
+            .port_forwards
+            .iter_mut()
+            .find(|forward| forward.container_port == container_port)
+        else {
+            return;
+        };
+
+        forward.error = None;
+        forward.task = Some(cx.spawn(|this, mut cx| async move {
+            let result = async {
+                let ip = docker
+                    .container_ip(&container_id)
+                    .await?
+                    .ok_or_else(|| anyhow::anyhow!("the container has no network address"))?;
+                let target = SocketAddr::new(ip, container_port);
+                port_forward::check_reachable(target).await?;
+                let listener = port_forward::listen(container_port).await?;
+                let host_port = listener.local_addr()?.port();
+                this.update(&mut cx, |this, cx| {
+                    if let Some(forward) = this.port_forward_mut(container_port) {
+                        forward.host_port = Some(host_port);
+                        cx.notify();
+                    }
+                })?;
+                cx.background_executor()
+                    .spawn(port_forward::forward_connections(listener, target))
+                    .await
+            }
+            .await;
+
+            if let Err(error) = result {
+                log::error!("failed to forward port {container_port}: {error:?}");
+                this.update(&mut cx, |this, cx| {
+                    if let Some(forward) = this.port_forward_mut(container_port) {
+                        forward.task = None;
+                        forward.host_port = None;
+                        forward.error = Some(error.to_string().into());
+                        cx.notify();
+                    }
+                })
+                .ok();
+            }
+        }));
+        cx.notify();
+    }
+
+    /// Stops forwarding the port, closing its connections. The row stays so
+    /// the port can be forwarded again.
+    fn remove_port_forward(&mut self, container_port: u16, cx: &mut ViewContext<Self>) {
+        if let Some(forward) = self.port_forward_mut(container_port) {
+            forward.task = None;
+            forward.host_port = None;
+            forward.error = None;
+            cx.notify();
+        }
+    }
+
+    fn port_forward_mut(&mut self, container_port: u16) -> Option<&mut PortForward> {
+        self.port_forwards
+            .iter_mut()
+            .find(|forward| forward.container_port == container_port)
+    }
+
+    fn is_rebuilding_dev_container(&self) -> bool {
+        self.rebuild
+            .as_ref()
+            .is_some_and(|rebuild| rebuild.task.is_some())
+    }
+
+    /// Replaces the workspace's dev container with one built from the current
+    /// configuration, showing the build's output as it goes.
+    fn rebuild_dev_container(&mut self, cx: &mut ViewContext<Self>) {
+        let (Some(docker), Some(dev_container)) =
+            (self.docker.clone(), self.dev_container.as_ref())
+        else {
+            return;
+        };
+        let Some(workspace) = self.workspace.upgrade() else {
+            return;
+        };
+        if self.reopen_in_container_task.is_some() || self.is_rebuilding_dev_container() {
+            return;
+        }
+
+        let name = dev_container.name();
+        let worktree_roots = workspace_roots(workspace.read(cx).project(), cx);
+        let fs = self.fs.clone();
+        let docker_host = self.active_docker_host(cx);
+        let view = cx.view().downgrade();
+        let output_list = ListState::new(0, ListAlignment::Bottom, px(1000.), move |ix, cx| {
+            view.upgrade()
+                .and_then(|view| {
+                    view.update(cx, |this, cx| this.render_rebuild_output_line(ix, cx))
+                })
+                .unwrap_or_else(|| div().into_any())
+        });
+        let task = cx.spawn(|this, mut cx| async move {
+            let (progress_tx, mut progress_rx) = mpsc::unbounded();
+            let rebuild = cx.background_executor().spawn(async move {
+                // Reread the configuration, since changing it is why users rebuild.
+                let dev_container = find_dev_container(fs.as_ref(), &worktree_roots)
+                    .await?
+                    .ok_or_else(|| anyhow::anyhow!("no dev container configuration found"))?;
+                let id = dev_container
+                    .rebuild(&docker, &docker_host, progress_tx)
+                    .await?;
+                anyhow::Ok((dev_container, id))
+            });
+            while let Some(line) = progress_rx.next().await {
+                if this
+                    .update(&mut cx, |this, cx| this.append_rebuild_output(line, cx))
+                    .is_err()
+                {
+                    return;
+                }
+            }
+            let result = rebuild.await;
+
+            this.update(&mut cx, |this, cx| {
+                if let Some(rebuild) = &mut this.rebuild {
+                    rebuild.task = None;
+                }
+                match result {
+                    Ok((dev_container, id)) => {
+                        let name = dev_container.name();
+                        this.append_rebuild_output(
+                            format!("Started container {}", &id[..id.len().min(12)]),
+                            cx,
+                        );
+                        this.show_toast(format!("Rebuilt dev container {name}"), cx);
+                        this.run_dev_container_commands(&dev_container, id.clone(), true, cx);
+                        this.dev_container = Some(dev_container);
+                        this.selected_container = Some(id);
+                    }
+                    Err(error) => {
+                        log::error!("failed to rebuild dev container: {error:?}");
+                        this.append_rebuild_output(format!("Rebuild failed: {error}"), cx);
+                    }
+                }
+                this.fetch_containers(cx);
+                cx.notify();
+            })
+            .ok();
+        });
+
+        self.view = PanelView::RebuildDevContainer;
+        self.rebuild = Some(RebuildDevContainer {
+            name,
+            output: Vec::new(),
+            output_list,
+            task: Some(task),
+        });
+        cx.notify();
+    }
+
+    fn append_rebuild_output(&mut self, line: String, cx: &mut ViewContext<Self>) {
+        if let Some(rebuild) = &mut self.rebuild {
+            let ix = rebuild.output.len();
+            rebuild.output_list.splice(ix..ix, 1);
+            rebuild.output.push(line.into());
+            cx.notify();
+        }
+    }
+
+    /// Dropping the rebuild's task stops it at the next step, killing a running build.
+    fn cancel_rebuild(&mut self, cx: &mut ViewContext<Self>) {
+        if let Some(rebuild) = &mut self.rebuild {
+            if rebuild.task.take().is_some() {
+                self.append_rebuild_output("Rebuild cancelled".into(), cx);
+            }
+        }
+    }
+
+    /// Runs the dev container's lifecycle commands in the container it started,
+    /// in order, stopping at the first that fails. `postCreateCommand` only runs
+    /// if the container was just `created`.
+    fn run_dev_container_commands(
+        &mut self,
+        dev_container: &DevContainer,
+        container_id: String,
+        created: bool,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+        let commands = dev_container.config.lifecycle_commands(created);
+        if commands.is_empty() {
+            return;
+        }
+
+        let view = cx.view().downgrade();
+        let output_list = ListState::new(0, ListAlignment::Bottom, px(1000.), move |ix, cx| {
+            view.upgrade()
+                .and_then(|view| {
+                    view.update(cx, |this, cx| {
+                        this.render_dev_container_command_output_line(ix, cx)
+                    })
+                })
+                .unwrap_or_else(|| div().into_any())
+        });
+        let task = cx.spawn({
+            let commands = commands.clone();
+            |this, mut cx| async move {
+                for (ix, (property, command)) in commands.into_iter().enumerate() {
+                    let started = this.update(&mut cx, |this, cx| {
+                        this.set_dev_container_command_status(ix, CommandStatus::Running, cx);
+                        this.append_dev_container_command_output(
+                            format!("Running {property}: {command}"),
+                            cx,
+                        );
+                    });
+                    if started.is_err() {
+                        return;
+                    }
+
+                    let result = async {
+                        let mut exec = docker.exec(&container_id, &command.argv()).await?;
+                        while let Some(lines) = exec.next_lines().await? {
+                            this.update(&mut cx, |this, cx| {
+                                for line in lines {
+                                    this.append_dev_container_command_output(line, cx);
+                                }
+                            })?;
+                        }
+                        docker.exec_exit_code(&exec.exec_id).await
+                    }
+                    .await;
+                    let failure = match result {
+                        Ok(Some(0)) => None,
+                        Ok(Some(code)) => Some(format!("{property} failed with exit code {code}")),
+                        Ok(None) => Some(format!("{property} exited without an exit code")),
+                        Err(error) => Some(format!("Failed to run {property}: {error}")),
+                    };
+                    let failed = failure.is_some();
+                    this.update(&mut cx, |this, cx| {
+                        if let Some(message) = failure {
+                            this.set_dev_container_command_status(ix, CommandStatus::Failed, cx);
+                            this.append_dev_container_command_output(message.clone(), cx);
+                            this.show_toast(message, cx);
+                        } else {
+                            this.set_dev_container_command_status(ix, CommandStatus::Succeeded, cx);
+                        }
+                    })
+                    .ok();
+                    if failed {
+                        break;
+                    }
+                }
+
+                this.update(&mut cx, |this, cx| {
+                    if let Some(commands) = &mut this.dev_container_commands {
+                        commands.task = None;
+                    }
+                    cx.notify();
+                })
+                .ok();
+            }
+        });
+
+        self.dev_container_commands = Some(DevContainerCommands {
+            name: dev_container.name(),
+            commands: commands
+                .into_iter()
+                .map(|(property, command)| DevContainerCommand {
+                    property,
+                    command,
+                    status: CommandStatus::Pending,
+                })
+                .collect(),
+            output: Vec::new(),
+            output_list,
+            task: Some(task),
+        });
+        if matches!(self.view, PanelView::List | PanelView::RebuildDevContainer) {
+            self.view = PanelView::DevContainerCommands;
+        }
+        cx.notify();
+    }
+
+    fn running_dev_container_command(&self) -> Option<&'static str> {
+        let commands = self.dev_container_commands.as_ref()?;
+        commands.task.as_ref()?;
+        commands
+            .commands
+            .iter()
+            .find(|command| command.status == CommandStatus::Running)
+            .map(|command| command.property)
+    }
+
+    fn set_dev_container_command_status(
+        &mut self,
+        ix: usize,
+        status: CommandStatus,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if let Some(command) = self
+            .dev_container_commands
+            .as_mut()
+            .and_then(|commands| commands.commands.get_mut(ix))
+        {
+            command.status = status;
+            cx.notify();
+        }
+    }
+
+    fn append_dev_container_command_output(&mut self, line: String, cx: &mut ViewContext<Self>) {
+        if let Some(commands) = &mut self.dev_container_commands {
+            let ix = commands.output.len();
+            commands.output_list.splice(ix..ix, 1);
+            commands.output.push(line.into());
+            cx.notify();
+        }
+    }
+
+    fn show_dev_container_commands(&mut self, cx: &mut ViewContext<Self>) {
+        if self.dev_container_commands.is_some() {
+            self.view = PanelView::DevContainerCommands;
+            cx.notify();
+        }
+    }
+
+    fn fetch_containers(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            self.set_connection(
+                ConnectionState::Disconnected {
+                    error: "Unsupported Docker host.".into(),
+                },
+                cx,
+            );
+            return;
+        };
+
+        // Health check output is only refetched when the health status changes.
+        let known_health_checks = self
+            .containers
+            .iter()
+            .filter_map(|container| {
+                let output = container.health_check_output.clone();
+                Some((container.id.clone(), (container.health?, output)))
+            })
+            .collect::<HashMap<_, _>>();
+        self.fetch_containers_task = cx.spawn(|this, mut cx| async move {
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    let containers = docker.list_containers(true).await?;
+                    // The list doesn't include the health checks' output, so it's
+                    // fetched separately, and concurrently, for the containers
+                    // with a health check.
+                    let mut health_check_outputs = HashMap::default();
+                    let mut stale = Vec::new();
+                    for container in &containers {
+                        let Some(health) = HealthStatus::from_status(&container.status) else {
+                            continue;
+                        };
+                        match known_health_checks.get(&container.id) {
+                            Some((known_health, output)) if *known_health == health => {
+                                if let Some(output) = output {
+                                    health_check_outputs
+                                        .insert(container.id.clone(), output.clone());
+                                }
+                            }
+                            _ => stale.push(container.id.as_str()),
+                        }
+                    }
+                    let outputs = futures::future::join_all(
+                        stale.iter().map(|id| docker.last_health_check_output(id)),
+                    )
+                    .await;
+                    for (id, output) in stale.into_iter().zip(outputs) {
+                        if let Some(output) = output.log_err().flatten() {
+                            health_check_outputs.insert(id.to_string(), output.into());
+                        }
+                    }
+                    anyhow::Ok((containers, health_check_outputs))
+                })
+                .await;
+            this.update(&mut cx, |this, cx| {
+                if this.refreshing {
+                    this.refreshing = false;
+                    cx.notify();
+                }
+                match result {
+                    Ok((containers, mut health_check_outputs)) => {
+                        let containers = containers
+                            .into_iter()
+                            .map(|summary| {
+                                let mut container = ContainerEntry::from(summary);
+                                container.health_check_output =
+                                    health_check_outputs.remove(&container.id);
+                                container
+                            })
+                            .collect();
+                        if this.set_containers(containers) {
+                            this.update_entries(cx);
+                            this.update_port_forwards(cx);
+                        }
+                        this.set_connection(ConnectionState::Connected, cx);
+                        this.update_stats_tasks(cx);
+                    }
+                    Err(error) => {
+                        log::debug!("failed to list Docker containers: {error:?}");
+                        this.last_connection_attempt = Some(OffsetDateTime::now_utc());
+                        this.containers.clear();
+                        this.update_entries(cx);
+                        this.update_port_forwards(cx);
+                        this.stats_tasks.clear();
+                        this.set_connection(
+                            ConnectionState::Disconnected {
+                                error: error.to_string().into(),
+                            },
+                            cx,
+                        );
+                        cx.notify();
+                    }
+                }
+            })
+            .ok();
+        });
+    }
+
+    fn set_connection(&mut self, connection: ConnectionState, cx: &mut ViewContext<Self>) {
+        if self.connection != connection {
+            self.connection = connection;
+            cx.notify();
+        }
+    }
+
+    fn retry_connection(&mut self, cx: &mut ViewContext<Self>) {
+        self.connect(cx);
+    }
+
+    /// Re-fetches the container list immediately, showing progress in the header.
+    fn refresh(&mut self, cx: &mut ViewContext<Self>) {
+        self.refreshing = true;
+        self.fetch_containers(cx);
+        self.fetch_tab(cx);
+        cx.notify();
+    }
+
+    fn set_tab(&mut self, tab: PanelTab, cx: &mut ViewContext<Self>) {
+        if self.tab == tab {
+            return;
+        }
+
+        self.tab = tab;
+        self.fetch_tab(cx);
+        self.serialize(cx);
+        cx.notify();
+    }
+
+    /// Fetches what the active tab lists besides containers, which are always fetched.
+    fn fetch_tab(&mut self, cx: &mut ViewContext<Self>) {
+        match self.tab {
+            PanelTab::Containers => {}
+            PanelTab::Images => self.fetch_images(cx),
+            PanelTab::Volumes => self.fetch_volumes(cx),
+        }
+    }
+
+    fn fetch_images(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+
+        self.fetch_images_task = cx.spawn(|this, mut cx| async move {
+            let result = cx
+                .background_executor()
+                .spawn(async move { docker.list_images().await })
+                .await;
+            this.update(&mut cx, |this, cx| {
+                match result {
+                    Ok(images) => this.images = image_entries(images),
+                    Err(error) => {
+                        log::debug!("failed to list Docker images: {error:?}");
+                        this.images.clear();
+                    }
+                }
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    fn fetch_volumes(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+
+        self.fetch_volumes_task = cx.spawn(|this, mut cx| async move {
+            let result = cx
+                .background_executor()
+                .spawn(async move { docker.list_volumes().await })
+                .await;
+            this.update(&mut cx, |this, cx| {
+                match result {
+                    Ok(volumes) => {
+                        this.volumes = volumes.into_iter().map(VolumeEntry::from).collect();
+                        this.volumes.sort_by(|a, b| a.name.cmp(&b.name));
+                    }
+                    Err(error) => {
+                        log::debug!("failed to list Docker volumes: {error:?}");
+                        this.volumes.clear();
+                    }
+                }
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    /// The names of the containers that mount the volume.
+    fn volume_users(&self, volume_name: &str) -> Vec<&str> {
+        self.containers
+            .iter()
+            .filter(|container| {
+                container
+                    .mounts
+                    .iter()
+                    .any(|mount| mount.name.as_deref() == Some(volume_name))
+            })
+            .map(|container| container.name.as_str())
+            .collect()
+    }
+
+    fn remove_volume(&mut self, name: String, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+        if self.volume_tasks.contains_key(&name) {
+            return;
+        }
+
+        let task = cx.spawn({
+            let name = name.clone();
+            |this, mut cx| async move {
+                let result = cx
+                    .background_executor()
+                    .spawn({
+                        let name = name.clone();
+                        async move { docker.remove_volume(&name).await }
+                    })
+                    .await;
+                this.update(&mut cx, |this, cx| {
+                    this.volume_tasks.remove(&name);
+                    if let Err(error) = result {
+                        this.show_toast(format!("Failed to remove volume {name}: {error}"), cx);
+                    }
+                    this.fetch_volumes(cx);
+                    cx.notify();
+                })
+                .ok();
+            }
+        });
+        self.volume_tasks.insert(name, task);
+        cx.notify();
+    }
+
+    /// Fetches the disk usage now, then every [`DISK_USAGE_POLL_INTERVAL`].
+    fn poll_disk_usage(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+
+        self.disk_usage_task = cx.spawn(|this, mut cx| async move {
+            loop {
+                let result = cx
+                    .background_executor()
+                    .spawn({
+                        let docker = docker.clone();
+                        async move { docker.disk_usage().await }
+                    })
+                    .await;
+                if this
+                    .update(&mut cx, |this, cx| match result {
+                        Ok(disk_usage) => {
+                            this.disk_usage = Some(disk_usage);
+                            cx.notify();
+                        }
+                        Err(error) => log::debug!("failed to fetch disk usage: {error:?}"),
+                    })
+                    .is_err()
+                {
+                    break;
+                }
+                cx.background_executor()
+                    .timer(DISK_USAGE_POLL_INTERVAL)
+                    .await;
+            }
+        });
+    }
+
+    /// Removes stopped containers, then the images and volumes no container
+    /// uses, after confirming.
+    fn reclaim_disk_space(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+        if self.reclaim_task.is_some() {
+            return;
+        }
+
+        let message = match self.disk_usage {
+            Some(disk_usage) => format!("Reclaim {}?", format_bytes(disk_usage.reclaimable)),
+            None => "Reclaim unused disk space?".to_string(),
+        };
+        let answer = cx.prompt(
+            PromptLevel::Warning,
+            &message,
+            Some(
+                "This removes all stopped containers, and the images and volumes no \
+                container uses. Volume data will be lost. This can't be undone.",
+            ),
+            &["Remove", "Cancel"],
+        );
+        self.reclaim_task = Some(cx.spawn(|this, mut cx| async move {
+            if answer.await != Ok(0) {
+                this.update(&mut cx, |this, _| this.reclaim_task = None)
+                    .ok();
+                return;
+            }
+
+            // Removing containers first frees their images and volumes.
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    let mut space_reclaimed = docker.prune_containers().await?.space_reclaimed;
+                    space_reclaimed += docker.prune_images().await?.space_reclaimed;
+                    space_reclaimed += docker.prune_volumes().await?.space_reclaimed;
+                    anyhow::Ok(space_reclaimed)
+                })
+                .await;
+            this.update(&mut cx, |this, cx| {
+                this.reclaim_task = None;
+                match result {
+                    Ok(space_reclaimed) => {
+                        this.show_toast(format!("Reclaimed {}", format_bytes(space_reclaimed)), cx)
+                    }
+                    Err(error) => {
+                        log::error!("failed to reclaim disk space: {error:?}");
+                        this.show_toast(format!("Failed to reclaim disk space: {error}"), cx);
+                    }
+                }
+                this.fetch_containers(cx);
+                this.fetch_tab(cx);
+                this.poll_disk_usage(cx);
+                cx.notify();
+            })
+            .ok();
+        }));
+        cx.notify();
+    }
+
+    /// Removes the volumes that aren't used by any container, after confirming.
+    fn prune_volumes(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+        if self.prune_volumes_task.is_some() {
+            return;
+        }
+
+        let answer = cx.prompt(
+            PromptLevel::Warning,
+            "Remove all unused volumes?",
+            Some("Their data will be lost. This can't be undone."),
+            &["Remove", "Cancel"],
+        );
+        self.prune_volumes_task = Some(cx.spawn(|this, mut cx| async move {
+            if answer.await != Ok(0) {
+                this.update(&mut cx, |this, _| this.prune_volumes_task = None)
+                    .ok();
+                return;
+            }
+
+            let result = cx
+                .background_executor()
+                .spawn(async move { docker.prune_volumes().await })
+                .await;
+            this.update(&mut cx, |this, cx| {
+                this.prune_volumes_task = None;
+                match result {
+                    Ok(PruneReport {
+                        removed,
+                        space_reclaimed,
+                    }) => {
+                        let volumes = if removed == 1 { "volume" } else { "volumes" };
+                        this.show_toast(
+                            format!(
+                                "Removed {removed} {volumes}, reclaiming {}",
+                                format_bytes(space_reclaimed)
+                            ),
+                            cx,
+                        );
+                    }
+                    Err(error) => {
+                        log::error!("failed to prune volumes: {error:?}");
+                        this.show_toast(format!("Failed to prune volumes: {error}"), cx);
+                    }
+                }
+                this.fetch_volumes(cx);
+                cx.notify();
+            })
+            .ok();
+        }));
+        cx.notify();
+    }
+
+    /// Starts a new container from the image with its default command.
+    fn run_image(&mut self, image: ImageEntry, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+        let key = image.key().to_string();
+        if self.image_tasks.contains_key(&key) {
+            return;
+        }
+
+        let task = cx.spawn({
+            let key = key.clone();
+            |this, mut cx| async move {
+                let result = cx
+                    .background_executor()
+                    .spawn({
+                        let key = key.clone();
+                        async move {
+                            let id = docker
+                                .create_container(&CreateContainerRequest {
+                                    image: key,
+                                    ..Default::default()
+                                })
+                                .await?;
+                            docker.start_container(&id).await?;
+                            anyhow::Ok(id)
+                        }
+                    })
+                    .await;
+                this.update(&mut cx, |this, cx| {
+                    this.image_tasks.remove(&key);
+                    match result {
+                        Ok(id) => this.show_toast(
+                            format!(
+                                "Started container {} from {}",
+                                &id[..id.len().min(12)],
+                                image.label()
+                            ),
+                            cx,
+                        ),
+                        Err(error) => {
+                            this.show_toast(format!("Failed to run {}: {error}", image.label()), cx)
+                        }
+                    }
+                    this.fetch_containers(cx);
+                    cx.notify();
+                })
+                .ok();
+            }
+        });
+        self.image_tasks.insert(key, task);
+        cx.notify();
+    }
+
+    /// Pulls the image named in the pull editor, showing each layer's progress
+    /// as it's downloaded.
+    fn pull_image(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+        if self.pull_task.is_some() {
+            return;
+        }
+        let reference = self.pull_editor.read(cx).text(cx).trim().to_string();
+        if reference.is_empty() {
+            return;
+        }
+        if reference.contains(char::is_whitespace) {
+            self.show_warning(format!("{reference} is not a valid image reference"), cx);
+            return;
+        }
+
+        self.pull_progress.clear();
+        self.pull_task = Some(cx.spawn(|this, mut cx| async move {
+            let result = async {
+                let mut pull = docker.pull_image_with_progress(&reference).await?;
+                while let Some(progress) = pull.next_progress().await? {
+                    this.update(&mut cx, |this, cx| this.update_pull_progress(progress, cx))?;
+                }
+                anyhow::Ok(())
+            }
+            .await;
+
+            this.update(&mut cx, |this, cx| {
+                this.pull_task = None;
+                this.pull_progress.clear();
+                match result {
+                    Ok(()) => {
+                        this.pull_editor.update(cx, |editor, cx| editor.clear(cx));
+                        this.show_toast(format!("Pulled {reference}"), cx);
+                    }
+                    Err(error) => {
+                        log::error!("failed to pull {reference}: {error:?}");
+                        this.show_warning(pull_error_message(&reference, &error), cx);
+                    }
+                }
+                this.fetch_images(cx);
+                cx.notify();
+            })
+            .ok();
+        }));
+        cx.notify();
+    }
+
+    /// Replaces the progress of the message's layer. Messages about the image as
+    /// a whole, which have no layer, replace each other.
+    fn update_pull_progress(&mut self, progress: PullProgress, cx: &mut ViewContext<Self>) {
+        match self
+            .pull_progress
+            .iter_mut()
+            .find(|existing| existing.id == progress.id)
+        {
+            Some(existing) => *existing = progress,
+            None => self.pull_progress.push(progress),
+        }
+        cx.notify();
+    }
+
+    /// The Dockerfiles in the workspace's worktrees, skipping ignored files.
+    fn find_dockerfiles(&self, cx: &AppContext) -> Vec<Dockerfile> {
+        let Some(workspace) = self.workspace.upgrade() else {
+            return Vec::new();
+        };
+        let project = workspace.read(cx).project().read(cx);
+        project
+            .visible_worktrees(cx)
+            .filter_map(|worktree| worktree.read(cx).as_local())
+            .flat_map(|worktree| {
+                worktree
+                    .files(false, 0)
+                    .filter(|entry| {
+                        entry
+                            .path
+                            .file_name()
+                            .and_then(|name| name.to_str())
+                            .is_some_and(is_dockerfile)
+                    })
+                    .map(move |entry| Dockerfile {
+                        worktree_root: worktree.abs_path().clone(),
+                        path: entry.path.clone(),
+                    })
+            })
+            .collect()
+    }
+
+    fn start_build_image(&mut self, cx: &mut ViewContext<Self>) {
+        let dockerfiles = self.find_dockerfiles(cx);
+        if dockerfiles.is_empty() {
+            self.show_warning("No Dockerfiles found in the workspace", cx);
+            return;
+        }
+
+        let tag_editor = cx.new_view(|cx| {
+            let mut editor = Editor::single_line(cx);
+            editor.set_placeholder_text("Tag, e.g. my-app:latest", cx);
+            editor
+        });
+        let context_editor = cx.new_view(|cx| {
+            let mut editor = Editor::single_line(cx);
+            editor.set_placeholder_text("Context (defaults to the worktree root)", cx);
+            editor
+        });
+        let view = cx.view().downgrade();
+        let output_list = ListState::new(0, ListAlignment::Bottom, px(1000.), move |ix, cx| {
+            view.upgrade()
+                .and_then(|view| view.update(cx, |this, cx| this.render_build_output_line(ix, cx)))
+                .unwrap_or_else(|| div().into_any())
+        });
+        cx.focus_view(&tag_editor);
+        self.view = PanelView::Build;
+        self.build = Some(BuildImage {
+            dockerfiles,
+            selected: 0,
+            tag_editor,
+            context_editor,
+            output: Vec::new(),
+            output_list,
+            task: None,
+        });
+        cx.notify();
+    }
+
+    fn select_dockerfile(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if let Some(build) = &mut self.build {
+            if build.task.is_none() && ix < build.dockerfiles.len() {
+                build.selected = ix;
+                cx.notify();
+            }
+        }
+    }
+
+    /// Builds the selected Dockerfile with the Docker CLI, streaming its output
+    /// into the build view.
+    fn build_image(&mut self, cx: &mut ViewContext<Self>) {
+        let docker_host = self.active_docker_host(cx);
+        let Some(build) = &mut self.build else {
+            return;
+        };
+        if build.task.is_some() {
+            return;
+        }
+        let tag = build.tag_editor.read(cx).text(cx).trim().to_string();
+        if tag.is_empty() {
+            return;
+        }
+        if tag.contains(char::is_whitespace) {
+            self.show_warning(format!("{tag} is not a valid image tag"), cx);
+            return;
+        }
+        let dockerfile = build.dockerfiles[build.selected].clone();
+        let context = dockerfile
+            .worktree_root
+            .join(build.context_editor.read(cx).text(cx).trim());
+
+        build.output.clear();
+        build.output_list.reset(0);
+        build.task = Some(cx.spawn(|this, mut cx| async move {
+            let result = async {
+                let mut process = docker::build_image(
+                    &docker_host,
+                    &dockerfile.worktree_root.join(&dockerfile.path),
+                    &tag,
+                    &context,
+                    &[],
+                )?;
+                while let Some(line) = process.next_line().await? {
+                    this.update(&mut cx, |this, cx| this.append_build_output(line, cx))?;
+                }
+                process.wait().await
+            }
+            .await;
+
+            this.update(&mut cx, |this, cx| {
+                if let Some(build) = &mut this.build {
+                    build.task = None;
+                }
+                match result {
+                    Ok(()) => {
+                        this.show_toast(format!("Built {tag}"), cx);
+                        this.fetch_images(cx);
+                    }
+                    Err(error) => {
+                        log::error!("failed to build {tag}: {error:?}");
+                        this.append_build_output(format!("Build failed: {error}"), cx);
+                    }
+                }
+                cx.notify();
+            })
+            .ok();
+        }));
+        cx.notify();
+    }
+
+    fn append_build_output(&mut self, line: String, cx: &mut ViewContext<Self>) {
+        if let Some(build) = &mut self.build {
+            let ix = build.output.len();
+            build.output_list.splice(ix..ix, 1);
+            build.output.push(line.into());
+            cx.notify();
+        }
+    }
+
+    /// Dropping the build's task kills the Docker CLI, which stops the build.
+    fn cancel_build(&mut self, cx: &mut ViewContext<Self>) {
+        if let Some(build) = &mut self.build {
+            if build.task.take().is_some() {
+                self.append_build_output("Build cancelled".into(), cx);
+            }
+        }
+    }
+
+    fn remove_image(&mut self, image: ImageEntry, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+        let key = image.key().to_string();
+        if self.image_tasks.contains_key(&key) {
+            return;
+        }
+
+        let task = cx.spawn({
+            let key = key.clone();
+            |this, mut cx| async move {
+                let result = cx
+                    .background_executor()
+                    .spawn({
+                        let key = key.clone();
+                        async move { docker.remove_image(&key).await }
+                    })
+                    .await;
+                this.update(&mut cx, |this, cx| {
+                    this.image_tasks.remove(&key);
+                    if let Err(error) = result {
+                        this.show_toast(format!("Failed to remove {}: {error}", image.label()), cx);
+                    }
+                    this.fetch_images(cx);
+                    cx.notify();
+                })
+                .ok();
+            }
+        });
+        self.image_tasks.insert(key, task);
+        cx.notify();
+    }
+
+    /// Replaces the listed containers, carrying over the latest stats sample of
+    /// containers that are still running. Returns whether anything changed.
+    fn set_containers(&mut self, mut containers: Vec<ContainerEntry>) -> bool {
+        for container in &mut containers {
+            if !container.is_running() {
+                continue;
+            }
+            container.stats = self
+                .containers
+                .iter()
+                .find(|existing| existing.id == container.id)
+                .and_then(|existing| existing.stats);
+        }
+
+        if containers == self.containers {
+            false
+        } else {
+            self.containers = containers;
+            true
+        }
+    }
+
+    /// Re-queries the container list on the configured interval while the panel is active.
+    fn schedule_refresh(&mut self, cx: &mut ViewContext<Self>) {
+        let refresh_interval = DockerPanelSettings::get_global(cx).refresh_interval;
+        if refresh_interval == 0 {
+            self.refresh_task = Task::ready(());
+            return;
+        }
+
+        self.refresh_task = cx.spawn(|this, mut cx| async move {
+            loop {
+                cx.background_executor()
+                    .timer(Duration::from_secs(refresh_interval))
+                    .await;
+                if this
+                    .update(&mut cx, |this, cx| this.fetch_containers(cx))
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        });
+    }
+
+    /// Follows the daemon's container events, announcing them and refreshing the
+    /// list as they arrive.
+    fn subscribe_to_events(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+
+        self.events_task = cx.spawn(|this, mut cx| async move {
+            let result = async {
+                let mut events = docker.container_events().await?;
+                // Containers that were sent a signal, whose exit code reflects being
+                // stopped rather than crashing.
+                let mut killed = HashSet::default();
+                while let Some(event) = events.next_event().await? {
+                    let message = match event {
+                        ContainerEvent::Started { name, .. } => {
+                            Some((format!("Container {name} started"), false))
+                        }
+                        ContainerEvent::Killed { id } => {
+                            killed.insert(id);
+                            None
+                        }
+                        ContainerEvent::Stopped { id, name } => {
+                            killed.remove(&id);
+                            Some((format!("Container {name} stopped"), false))
+                        }
+                        ContainerEvent::Died {
+                            id,
+                            name,
+                            exit_code,
+                        } => {
+                            if killed.contains(&id) {
+                                // A `stop` event follows.
+                                None
+                            } else if exit_code == 0 {
+                                Some((format!("Container {name} exited"), false))
+                            } else {
+                                Some((
+                                    format!("Container {name} died with exit code {exit_code}"),
+                                    true,
+                                ))
+                            }
+                        }
+                    };
+
+                    this.update(&mut cx, |this, cx| {
+                        match message {
+                            Some((message, true)) => this.show_warning(message, cx),
+                            Some((message, false)) => this.show_toast(message, cx),
+                            None => {}
+                        }
+                        this.fetch_containers(cx);
+                    })?;
+                }
+                anyhow::Ok(())
+            }
+            .await;
+
+            if let Err(error) = result {
+                log::debug!("stopped following Docker events: {error:?}");
+            }
+        });
+    }
+
+    /// Keeps exactly one stats poller alive for each listed container, so
+    /// stopped containers lose theirs and repeated toggling never piles them up.
+    fn update_stats_tasks(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+        if !self.active {
+            self.stats_tasks.clear();
+            return;
+        }
+
+        let containers = &self.containers;
+        self.stats_tasks.retain(|id, _| {
+            containers
+                .iter()
+                .any(|container| &container.id == id && container.is_running())
+        });
+        for container in &self.containers {
+            if !container.is_running() || self.stats_tasks.contains_key(&container.id) {
+                continue;
+            }
+
+            let container_id = container.id.clone();
+            let docker = docker.clone();
+            let task = cx.spawn(|this, mut cx| async move {
+                loop {
+                    let stats = cx
+                        .background_executor()
+                        .spawn({
+                            let docker = docker.clone();
+                            let container_id = container_id.clone();
+                            async move { docker.container_stats(&container_id).await }
+                        })
+                        .await;
+                    let updated = this.update(&mut cx, |this, cx| {
+                        if let Some(container) = this
+                            .containers
+                            .iter_mut()
+                            .find(|container| container.id == container_id)
+                        {
+                            container.stats = stats
+                                .map_err(|error| {
+                                    log::debug!(
+                                        "failed to fetch stats for {container_id}: {error:?}"
+                                    )
+                                })
+                                .ok()
+                                .map(Into::into);
+                            if this.sort.key == SortKey::Cpu {
+                                this.update_entries(cx);
+                            }
+                            cx.notify();
+                        }
+                    });
+                    if updated.is_err() {
+                        break;
+                    }
+                    cx.background_executor().timer(STATS_POLL_INTERVAL).await;
+                }
+            });
+            self.stats_tasks.insert(container.id.clone(), task);
+        }
+    }
+
+    fn run_container_action(
+        &mut self,
+        container_id: String,
+        action: ContainerAction,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+        if self.lifecycle_tasks.contains_key(&container_id) {
+            return;
+        }
+
+        let task = cx.spawn({
+            let container_id = container_id.clone();
+            |this, mut cx| async move {
+                let result = cx
+                    .background_executor()
+                    .spawn({
+                        let container_id = container_id.clone();
+                        async move {
+                            action.perform(&docker, &container_id).await?;
+                            docker.container(&container_id).await
+                        }
+                    })
+                    .await;
+                this.update(&mut cx, |this, cx| {
+                    this.lifecycle_tasks.remove(&container_id);
+                    let started =
+                        matches!(action, ContainerAction::Start | ContainerAction::Restart)
+                            && matches!(result, Ok(Some(_)));
+                    if let (Ok(_), ContainerAction::Kill { signal }) = (&result, action) {
+                        let name = this.container_name(&container_id);
+                        this.show_toast(format!("Sent {} to {name}", signal.name()), cx);
+                    }
+                    match result {
+                        Ok(Some(summary)) => {
+                            if let Some(container) = this
+                                .containers
+                                .iter_mut()
+                                .find(|container| container.id == container_id)
+                            {
+                                let stats = container.stats.take();
+                                let health_check_output = container.health_check_output.take();
+                                *container = summary.into();
+                                if container.is_running() {
+                                    container.stats = stats;
+                                }
+                                if container.health.is_some() {
+                                    container.health_check_output = health_check_output;
+                                }
+                            }
+                        }
+                        Ok(None) => this
+                            .containers
+                            .retain(|container| container.id != container_id),
+                        Err(error) => {
+                            let name = this
+                                .containers
+                                .iter()
+                                .find(|container| container.id == container_id)
+                                .map_or(container_id.as_str(), |container| container.name.as_str());
+                            let message =
+                                format!("Failed to {} container {name}: {error}", action.verb());
+                            this.show_toast(message, cx);
+                        }
+                    }
+                    this.update_entries(cx);
+                    this.update_stats_tasks(cx);
+                    if started {
+                        let dev_container = this.dev_container.clone().filter(|_| {
+                            this.running_dev_container()
+                                .is_some_and(|container| container.id == container_id)
+                        });
+                        if let Some(dev_container) = dev_container {
+                            this.run_dev_container_commands(
+                                &dev_container,
+                                container_id.clone(),
+                                false,
+                                cx,
+                            );
+                        }
+                    }
+                    cx.notify();
+                })
+                .ok();
+            }
+        });
+        self.lifecycle_tasks.insert(container_id, task);
+        cx.notify();
+    }
+
+    /// Stops the work the panel only does while it's open: polling, following
+    /// logs and subscribing to events, which holds a connection to the daemon.
+    fn cancel_background_tasks(&mut self) {
+        self.refresh_task = Task::ready(());
+        self.events_task = Task::ready(());
+        self.disk_usage_task = Task::ready(());
+        self.logs_task = Task::ready(());
+        self.stats_tasks.clear();
+    }
+
+    /// Stops the background work and closes the connection to the daemon as
+    /// soon as the panel is released.
+    fn release(&mut self, _: AnyWindowHandle, _: &mut AppContext) {
+        self.cancel_background_tasks();
+        self.docker = None;
+    }
+
+    fn open_logs(&mut self, container_id: String, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+
+        self.view = PanelView::Logs {
+            container_id: container_id.clone(),
+        };
+        self.log_lines.clear();
+        self.log_list.reset(0);
+        self.follow_logs = true;
+        self.logs_task = cx.spawn(|this, mut cx| async move {
+            let result = async {
+                let mut logs = docker.container_logs(&container_id).await?;
+                while let Some(lines) = logs.next_lines().await? {
+                    this.update(&mut cx, |this, cx| this.append_log_lines(lines, cx))?;
+                }
+                anyhow::Ok(())
+            }
+            .await;
+
+            if let Err(error) = result {
+                log::debug!("failed to follow logs of {container_id}: {error:?}");
+                this.update(&mut cx, |this, cx| {
+                    this.append_log_lines(vec![format!("Failed to stream logs: {error}")], cx)
+                })
+                .ok();
+            }
+        });
+        cx.notify();
+    }
+
+    fn append_log_lines(&mut self, lines: Vec<String>, cx: &mut ViewContext<Self>) {
+        let start = self.log_lines.len();
+        self.log_list.splice(start..start, lines.len());
+        self.log_lines
+            .extend(lines.into_iter().map(SharedString::from));
+        let overflow = self.log_lines.len().saturating_sub(MAX_LOG_LINES);
+        if overflow > 0 {
+            self.log_lines.drain(..overflow);
+            self.log_list.splice(0..overflow, 0);
+        }
+        cx.notify();
+    }
+
+    /// Replaces the row's controls with a prompt to confirm the removal, since it
+    /// can't be undone.
+    fn request_removal(&mut self, container_id: String, cx: &mut ViewContext<Self>) {
+        self.confirming_removal = Some(container_id);
+        cx.notify();
+    }
+
+    fn confirm_removal(&mut self, force: bool, cx: &mut ViewContext<Self>) {
+        if let Some(container_id) = self.confirming_removal.take() {
+            self.run_container_action(container_id, ContainerAction::Remove { force }, cx);
+        }
+    }
+
+    fn cancel_removal(&mut self, cx: &mut ViewContext<Self>) {
+        self.confirming_removal = None;
+        cx.notify();
+    }
+
+    /// Replaces the container's name with an editor for renaming it.
+    fn start_rename(&mut self, container_id: String, cx: &mut ViewContext<Self>) {
+        let name = self.container_name(&container_id);
+        let editor = cx.new_view(|cx| {
+            let mut editor = Editor::single_line(cx);
+            editor.set_text(name, cx);
+            editor.select_all(&editor::actions::SelectAll, cx);
+            editor
+        });
+        let subscription = cx.subscribe(&editor, |this, _, event, cx| {
+            if let EditorEvent::BufferEdited = event {
+                if let Some(rename) = &mut this.renaming {
+                    rename.error = None;
+                    cx.notify();
+                }
+            }
+        });
+        cx.focus_view(&editor);
+        self.renaming = Some(Rename {
+            container_id,
+            editor,
+            error: None,
+            _subscription: subscription,
+        });
+        cx.notify();
+    }
+
+    fn confirm_rename(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+        let Some(rename) = &self.renaming else {
+            return;
+        };
+        let container_id = rename.container_id.clone();
+        if self.lifecycle_tasks.contains_key(&container_id) {
+            return;
+        }
+
+        let new_name = rename.editor.read(cx).text(cx).trim().to_string();
+        if new_name == self.container_name(&container_id) {
+            self.cancel_rename(cx);
+            return;
+        }
+        if !is_valid_container_name(&new_name) {
+            if let Some(rename) = &mut self.renaming {
+                rename.error = Some(
+                    "Names start with a letter or digit, followed by letters, digits, _, . or -"
+                        .into(),
+                );
+            }
+            cx.notify();
+            return;
+        }
+
+        let task = cx.spawn({
+            let container_id = container_id.clone();
+            |this, mut cx| async move {
+                let result = cx
+                    .background_executor()
+                    .spawn({
+                        let container_id = container_id.clone();
+                        let new_name = new_name.clone();
+                        async move { docker.rename_container(&container_id, &new_name).await }
+                    })
+                    .await;
+                this.update(&mut cx, |this, cx| {
+                    this.lifecycle_tasks.remove(&container_id);
+                    match result {
+                        Ok(()) => {
+                            if let Some(container) = this
+                                .containers
+                                .iter_mut()
+                                .find(|container| container.id == container_id)
+                            {
+                                container.name = new_name;
+                            }
+                            if this
+                                .renaming
+                                .as_ref()
+                                .is_some_and(|rename| rename.container_id == container_id)
+                            {
+                                this.renaming = None;
+                            }
+                            this.update_entries(cx);
+                        }
+                        Err(error) => {
+                            if let Some(rename) = &mut this.renaming {
+                                rename.error = Some(error.to_string().into());
+                            }
+                        }
+                    }
+                    cx.notify();
+                })
+                .ok();
+            }
+        });
+        self.lifecycle_tasks.insert(container_id, task);
+        cx.notify();
+    }
+
+    fn cancel_rename(&mut self, cx: &mut ViewContext<Self>) {
+        if self.renaming.take().is_some() {
+            cx.focus_self();
+            cx.notify();
+        }
+    }
+
+    /// Asks for the path of a file in the container, to copy it to the host.
+    fn start_copy_from_container(&mut self, container_id: String, cx: &mut ViewContext<Self>) {
+        let path_editor = cx.new_view(|cx| {
+            let mut editor = Editor::single_line(cx);
+            editor.set_placeholder_text("Path in the container, e.g. /var/log/app.log", cx);
+            editor
+        });
+        cx.focus_view(&path_editor);
+        self.copy_from_container = Some(CopyFromContainer {
+            container_id,
+            path_editor,
+            progress: None,
+            task: None,
+            progress_task: Task::ready(()),
+        });
+        cx.notify();
+    }
+
+    /// Prompts for where to save the file, then copies it there. The platform's
+    /// save prompt can't suggest a file name, so choosing a directory keeps the
+    /// file's name.
+    fn confirm_copy_from_container(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+        let Some(workspace) = self.workspace.upgrade() else {
+            return;
+        };
+        let Some(copy) = &self.copy_from_container else {
+            return;
+        };
+        if copy.task.is_some() {
+            return;
+        }
+        let source = copy.path_editor.read(cx).text(cx).trim().to_string();
+        if source.is_empty() {
+            return;
+        }
+
+        let container_id = copy.container_id.clone();
+        let name = self.container_name(&container_id);
+        let file_name = Path::new(&source)
+            .file_name()
+            .map_or_else(|| name.clone(), |name| name.to_string_lossy().into_owned());
+        let directory = workspace_roots(workspace.read(cx).project(), cx)
+            .first()
+            .map_or_else(|| paths::HOME.clone(), |root| root.to_path_buf());
+        let destination = cx.prompt_for_new_path(&directory);
+        let fs = self.fs.clone();
+        let task = cx.spawn(|this, mut cx| async move {
+            let result = async {
+                let Some(mut destination) = destination.await.ok().flatten() else {
+                    return Ok(None);
+                };
+                if fs.is_dir(&destination).await {
+                    destination.push(&file_name);
+                }
+
+                let mut file = docker.copy_from_container(&container_id, &source).await?;
+                let progress = (file.bytes_read(), file.size());
+                this.update(&mut cx, |this, cx| this.show_copy_progress(progress, cx))?;
+                cx.background_executor()
+                    .spawn({
+                        let destination = destination.clone();
+                        async move { fs.create_file_with(&destination, Pin::new(&mut file)).await }
+                    })
+                    .await?;
+                anyhow::Ok(Some(destination))
+            }
+            .await;
+
+            this.update(&mut cx, |this, cx| {
+                match result {
+                    Ok(Some(destination)) => {
+                        this.copy_from_container = None;
+                        this.show_toast(
+                            format!("Copied {source} to {}", destination.display()),
+                            cx,
+                        );
+                    }
+                    // The save prompt was dismissed, so let the user pick again.
+                    Ok(None) => {
+                        if let Some(copy) = &mut this.copy_from_container {
+                            copy.task = None;
+                        }
+                    }
+                    Err(error) => {
+                        log::error!("failed to copy {source} from {container_id}: {error:?}");
+                        this.copy_from_container = None;
+                        this.show_toast(
+                            format!("Failed to copy {source} from {name}: {error}"),
+                            cx,
+                        );
+                    }
+                }
+                cx.notify();
+            })
+            .ok();
+        });
+        if let Some(copy) = &mut self.copy_from_container {
+            copy.task = Some(task);
+        }
+        cx.notify();
+    }
+
+    fn show_copy_progress(&mut self, progress: (Arc<AtomicU64>, u64), cx: &mut ViewContext<Self>) {
+        let Some(copy) = &mut self.copy_from_container else {
+            return;
+        };
+        copy.progress = Some(progress);
+        copy.progress_task = cx.spawn(|this, mut cx| async move {
+            loop {
+                cx.background_executor().timer(COPY_PROGRESS_INTERVAL).await;
+                if this.update(&mut cx, |_, cx| cx.notify()).is_err() {
+                    break;
+                }
+            }
+        });
+        cx.notify();
+    }
+
+    /// Dropping the copy's task abandons it, leaving whatever was written so far.
+    fn cancel_copy_from_container(&mut self, cx: &mut ViewContext<Self>) {
+        if self.copy_from_container.take().is_some() {
+            cx.focus_self();
+            cx.notify();
+        }
+    }
+
+    /// Asks for the `repo:tag` to commit the container to.
+    fn start_commit(&mut self, container_id: String, cx: &mut ViewContext<Self>) {
+        let reference_editor = cx.new_view(|cx| {
+            let mut editor = Editor::single_line(cx);
+            editor.set_placeholder_text("Image to create, e.g. my-app:snapshot", cx);
+            editor
+        });
+        cx.focus_view(&reference_editor);
+        self.committing = Some(CommitContainer {
+            container_id,
+            reference_editor,
+            task: None,
+        });
+        cx.notify();
+    }
+
+    fn confirm_commit(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+        let Some(commit) = &self.committing else {
+            return;
+        };
+        if commit.task.is_some() {
+            return;
+        }
+        let reference = commit.reference_editor.read(cx).text(cx).trim().to_string();
+        if reference.is_empty() {
+            return;
+        }
+
+        let container_id = commit.container_id.clone();
+        let name = self.container_name(&container_id);
+        let task = cx.spawn(|this, mut cx| async move {
+            let result = cx
+                .background_executor()
+                .spawn({
+                    let reference = reference.clone();
+                    async move { docker.commit_container(&container_id, &reference).await }
+                })
+                .await;
+            this.update(&mut cx, |this, cx| {
+                this.committing = None;
+                match result {
+                    Ok(_) => this.show_toast(format!("Committed {name} to {reference}"), cx),
+                    Err(error) => {
+                        log::error!("failed to commit {name} to {reference}: {error:?}");
+                        this.show_toast(format!("Failed to commit {name}: {error}"), cx);
+                    }
+                }
+                this.fetch_images(cx);
+                cx.notify();
+            })
+            .ok();
+        });
+        if let Some(commit) = &mut self.committing {
+            commit.task = Some(task);
+        }
+        cx.notify();
+    }
+
+    fn cancel_commit(&mut self, cx: &mut ViewContext<Self>) {
+        if self.committing.take().is_some() {
+            cx.focus_self();
+            cx.notify();
+        }
+    }
+
+    fn dispatch_context(&self, cx: &ViewContext<Self>) -> KeyContext {
+        let mut dispatch_context = KeyContext::new_with_defaults();
+        dispatch_context.add("DockerPanel");
+        // Editors in the panel, such as the filter, take the focus from the list.
+        if self.focus_handle.is_focused(cx) {
+            dispatch_context.add("not_editing");
+        } else {
+            dispatch_context.add("editing");
+        }
+        dispatch_context
+    }
+
+    fn select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
+        self.select_adjacent_container(true, cx);
+    }
+
+    fn select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
+        self.select_adjacent_container(false, cx);
+    }
+
+    /// Moves the selection to the next or previous container in the list,
+    /// skipping group headers. Selects the first or last one if none is selected.
+    fn select_adjacent_container(&mut self, forward: bool, cx: &mut ViewContext<Self>) {
+        if self.view != PanelView::List || self.tab != PanelTab::Containers {
+            return;
+        }
+
+        let current_ix = self.selected_container.as_ref().and_then(|selected| {
+            self.list_entries.iter().position(|entry| {
+                matches!(entry, ListEntry::Container { container_id } if container_id == selected)
+            })
+        });
+        let candidates = self
+            .list_entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| matches!(entry, ListEntry::Container { .. }));
+        let next = if forward {
+            candidates.find(|(ix, _)| current_ix.map_or(true, |current_ix| *ix > current_ix))
+        } else {
+            candidates
+                .filter(|(ix, _)| current_ix.map_or(true, |current_ix| *ix < current_ix))
+                .last()
+        };
+
+        if let Some((ix, ListEntry::Container { container_id })) = next {
+            self.selected_container = Some(container_id.clone());
+            self.container_list.scroll_to_reveal_item(ix);
+            cx.notify();
+        }
+    }
+
+    fn open_selected_logs(&mut self, _: &OpenLogs, cx: &mut ViewContext<Self>) {
+        if let Some(container_id) = self.keyboard_target() {
+            self.open_logs(container_id, cx);
+        }
+    }
+
+    fn stop_selected(&mut self, _: &StopSelected, cx: &mut ViewContext<Self>) {
+        if let Some(container_id) = self.keyboard_target() {
+            self.run_container_action(container_id, ContainerAction::Stop, cx);
+        }
+    }
+
+    fn restart_selected(&mut self, _: &RestartSelected, cx: &mut ViewContext<Self>) {
+        if let Some(container_id) = self.keyboard_target() {
+            self.run_container_action(container_id, ContainerAction::Restart, cx);
+        }
+    }
+
+    /// The selected container, if the container list is showing.
+    fn keyboard_target(&self) -> Option<String> {
+        if self.view != PanelView::List || self.tab != PanelTab::Containers {
+            return None;
+        }
+        self.selected_container.clone()
+    }
+
+    /// Leaving select mode clears the selection.
+    fn toggle_select_mode(&mut self, cx: &mut ViewContext<Self>) {
+        self.selecting = !self.selecting;
+        if !self.selecting {
+            self.selected.clear();
+            self.confirming_bulk_removal = false;
+        }
+        cx.notify();
+    }
+
+    fn toggle_selected(&mut self, container_id: String, cx: &mut ViewContext<Self>) {
+        if !self.selected.remove(&container_id) {
+            self.selected.insert(container_id);
+        }
+        if self.selected.is_empty() {
+            self.confirming_bulk_removal = false;
+        }
+        cx.notify();
+    }
+
+    /// Runs `action` on all selected containers at once, then sums up how it went.
+    fn run_bulk_action(&mut self, action: ContainerAction, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+        if self.bulk_action_task.is_some() {
+            return;
+        }
+
+        self.confirming_bulk_removal = false;
+        let tasks = self
+            .containers
+            .iter()
+            .filter(|container| self.selected.contains(&container.id))
+            .map(|container| {
+                let action = match action {
+                    // Only force the removal of the containers that need it.
+                    ContainerAction::Remove { .. } => ContainerAction::Remove {
+                        force: container.is_running() || container.is_paused(),
+                    },
+                    action => action,
+                };
+                let container_id = container.id.clone();
+                let docker = docker.clone();
+                cx.background_executor().spawn(async move {
+                    let result = action.perform(&docker, &container_id).await;
+                    if let Err(error) = &result {
+                        log::error!(
+                            "failed to {} container {container_id}: {error:?}",
+                            action.verb()
+                        );
+                    }
+                    result
+                })
+            })
+            .collect::<Vec<_>>();
+        if tasks.is_empty() {
+            return;
+        }
+
+        self.bulk_action_task = Some(cx.spawn(|this, mut cx| async move {
+            let results = futures::future::join_all(tasks).await;
+            let failed = results.iter().filter(|result| result.is_err()).count();
+            let succeeded = results.len() - failed;
+            this.update(&mut cx, |this, cx| {
+                this.bulk_action_task = None;
+                let mut message = format!("{succeeded} {}", action.past_tense());
+                if failed > 0 {
+                    message.push_str(&format!(", {failed} failed"));
+                }
+                this.show_toast(message, cx);
+                this.fetch_containers(cx);
+                cx.notify();
+            })
+            .ok();
+        }));
+        cx.notify();
+    }
+
+    /// Removes the stopped containers matching the filter, after confirming how many
+    /// there are. Without a filter, this prunes all stopped containers.
+    fn prune_stopped(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+        if self.prune_task.is_some() {
+            return;
+        }
+
+        let query = self.filter.trim().to_lowercase();
+        let container_ids = self
+            .containers
+            .iter()
+            .filter(|container| container.is_stopped() && container.matches(&query))
+            .map(|container| container.id.clone())
+            .collect::<Vec<_>>();
+        if container_ids.is_empty() {
+            self.show_toast("No stopped containers to remove", cx);
+            return;
+        }
+
+        let count = container_ids.len();
+        let message = if count == 1 {
+            "Remove 1 stopped container?".to_string()
+        } else {
+            format!("Remove {count} stopped containers?")
+        };
+        let answer = cx.prompt(
+            PromptLevel::Warning,
+            &message,
+            Some("This can't be undone."),
+            &["Remove", "Cancel"],
+        );
+        self.prune_task = Some(cx.spawn(|this, mut cx| async move {
+            if answer.await != Ok(0) {
+                this.update(&mut cx, |this, _| this.prune_task = None).ok();
+                return;
+            }
+
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    if query.is_empty() {
+                        docker.prune_containers().await
+                    } else {
+                        docker.prune_containers_by_id(&container_ids).await
+                    }
+                })
+                .await;
+            this.update(&mut cx, |this, cx| {
+                this.prune_task = None;
+                match result {
+                    Ok(PruneReport {
+                        removed,
+                        space_reclaimed,
+                    }) => {
+                        let containers = if removed == 1 {
+                            "container"
+                        } else {
+                            "containers"
+                        };
+                        this.show_toast(
+                            format!(
+                                "Removed {removed} {containers}, reclaiming {}",
+                                format_bytes(space_reclaimed)
+                            ),
+                            cx,
+                        );
+                    }
+                    Err(error) => {
+                        log::error!("failed to prune containers: {error:?}");
+                        this.show_toast(format!("Failed to prune containers: {error}"), cx);
+                    }
+                }
+                this.fetch_containers(cx);
+                cx.notify();
+            })
+            .ok();
+        }));
+        cx.notify();
+    }
+
+    fn deploy_container_context_menu(
+        &mut self,
+        position: Point<Pixels>,
+        container_id: String,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let Some(container) = self
+            .containers
+            .iter()
+            .find(|container| container.id == container_id)
+            .cloned()
+        else {
+            return;
+        };
+
+        let this = cx.view().clone();
+        let context_menu = ContextMenu::build(cx, |mut context_menu, cx| {
+            // These go through the same handler as the row's buttons, which ignores
+            // containers that already have an action in flight.
+            let action_entry = |context_menu: ContextMenu, label: &'static str, action| {
+                let container_id = container.id.clone();
+                context_menu.entry(
+                    label,
+                    None,
+                    cx.handler_for(&this, move |this, cx| {
+                        this.run_container_action(container_id.clone(), action, cx)
+                    }),
+                )
+            };
+            context_menu = if container.is_running() {
+                let context_menu = action_entry(context_menu, "Stop", ContainerAction::Stop);
+                let context_menu = action_entry(context_menu, "Restart", ContainerAction::Restart);
+                action_entry(context_menu, "Pause", ContainerAction::Pause)
+            } else if container.is_paused() {
+                let context_menu = action_entry(context_menu, "Unpause", ContainerAction::Unpause);
+                action_entry(context_menu, "Stop", ContainerAction::Stop)
+            } else {
+                action_entry(context_menu, "Start", ContainerAction::Start)
+            };
+            if container.is_running() || container.is_paused() {
+                context_menu = action_entry(
+                    context_menu.separator(),
+                    "Kill",
+                    ContainerAction::Kill {
+                        signal: Signal::default(),
+                    },
+                )
+                .header("Kill with Signal");
+                for signal in Signal::ALL {
+                    context_menu = action_entry(
+                        context_menu,
+                        signal.name(),
+                        ContainerAction::Kill { signal },
+                    );
+                }
+            }
+
+            let id = container.id.clone();
+            let name = container.name.clone();
+            context_menu
+                .separator()
+                .entry(
+                    "View Logs",
+                    None,
+                    cx.handler_for(&this, {
+                        let container_id = container.id.clone();
+                        move |this, cx| this.open_logs(container_id.clone(), cx)
+                    }),
+                )
+                .entry(
+                    "Inspect",
+                    None,
+                    cx.handler_for(&this, {
+                        let container_id = container.id.clone();
+                        move |this, cx| this.open_inspect(container_id.clone(), cx)
+                    }),
+                )
+                .entry("Copy ID", None, move |cx| {
+                    cx.write_to_clipboard(ClipboardItem::new(id.clone()))
+                })
+                .entry("Copy Name", None, move |cx| {
+                    cx.write_to_clipboard(ClipboardItem::new(name.clone()))
+                })
+                .entry(
+                    "Rename…",
+                    None,
+                    cx.handler_for(&this, {
+                        let container_id = container.id.clone();
+                        move |this, cx| this.start_rename(container_id.clone(), cx)
+                    }),
+                )
+                .entry(
+                    "Commit to Image…",
+                    None,
+                    cx.handler_for(&this, {
+                        let container_id = container.id.clone();
+                        move |this, cx| this.start_commit(container_id.clone(), cx)
+                    }),
+                )
+                .entry(
+                    "Copy File From Container…",
+                    None,
+                    cx.handler_for(&this, {
+                        let container_id = container.id.clone();
+                        move |this, cx| this.start_copy_from_container(container_id.clone(), cx)
+                    }),
+                )
+                .separator()
+                .entry(
+                    "Remove…",
+                    None,
+                    cx.handler_for(&this, {
+                        let container_id = container.id.clone();
+                        move |this, cx| this.request_removal(container_id.clone(), cx)
+                    }),
+                )
+        });
+
+        cx.focus_view(&context_menu);
+        let subscription =
+            cx.subscribe(&context_menu, |this, _, _: &DismissEvent, cx| {
+                if this.context_menu.as_ref().is_some_and(|context_menu| {
+                    context_menu.0.focus_handle(cx).contains_focused(cx)
+                }) {
+                    cx.focus_self();
+                }
+                this.context_menu.take();
+                cx.notify();
+            });
+        self.context_menu = Some((context_menu, position, subscription));
+        cx.notify();
+    }
+
+    /// Runs an interactive shell in the container in a new terminal.
+    fn open_shell(&mut self, container_id: String, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+        let Some(terminal_panel) = self
+            .workspace
+            .upgrade()
+            .and_then(|workspace| workspace.read(cx).panel::<TerminalPanel>(cx))
+        else {
+            return;
+        };
+
+        let name = self
+            .containers
+            .iter()
+            .find(|container| container.id == container_id)
+            .map_or_else(|| container_id.clone(), |container| container.name.clone());
+        let shell = DockerPanelSettings::get_global(cx).default_shell.clone();
+        let command_label = format!("docker exec -it {name} {shell}");
+        let spawn_in_terminal = SpawnInTerminal {
+            id: TaskId(format!("docker-exec-{container_id}")),
+            full_label: command_label.clone(),
+            label: format!("Shell: {name}"),
+            command: "docker".into(),
+            args: vec![
+                "exec".into(),
+                "-it".into(),
+                container_id.clone(),
+                shell.clone(),
+            ],
+            command_label,
+            cwd: None,
+            // Make the CLI talk to the same daemon as the panel.
+            env: HashMap::from_iter([("DOCKER_HOST".into(), self.active_docker_host(cx))]),
+            use_new_terminal: true,
+            allow_concurrent_runs: true,
+            reveal: RevealStrategy::Always,
+        };
+
+        cx.spawn(|this, mut cx| async move {
+            let result = async {
+                // Check the shell can be run before opening a terminal, since the
+                // shell's own exit code says nothing about whether `docker exec`
+                // worked, e.g. after `exit 1`.
+                let check = [shell.clone(), "-c".into(), "exit 0".into()];
+                let status = cx
+                    .background_executor()
+                    .spawn(async move { docker.exec_status(&container_id, &check).await })
+                    .await?;
+                if status != Some(0) {
+                    anyhow::bail!("{shell} can't be run in the container");
+                }
+                let terminal = terminal_panel.update(&mut cx, |terminal_panel, cx| {
+                    terminal_panel.spawn_in_new_terminal(spawn_in_terminal, cx)
+                })?;
+                terminal.await?;
+                anyhow::Ok(())
+            }
+            .await;
+
+            if let Err(error) = result {
+                this.update(&mut cx, |this, cx| {
+                    this.show_toast(format!("Failed to open a shell in {name}: {error}"), cx)
+                })
+                .ok();
+            }
+        })
+        .detach();
+    }
+
+    fn toggle_follow_logs(&mut self, cx: &mut ViewContext<Self>) {
+        self.follow_logs = !self.follow_logs;
+        if self.follow_logs {
+            // The list sticks to the bottom once it's scrolled all the way down.
+            self.log_list.scroll_to(ListOffset {
+                item_ix: self.log_lines.len(),
+                offset_in_item: px(0.),
+            });
+        } else if let Some(last_ix) = self.log_lines.len().checked_sub(1) {
+            // Pin the line currently at the bottom, so new lines accumulate below it.
+            self.log_list.scroll_to(ListOffset::default());
+            self.log_list.scroll_to_reveal_item(last_ix);
+        }
+        cx.notify();
+    }
+
+    /// Copies the latest buffered log lines, up to `max_copied_log_lines`.
+    fn copy_logs(&mut self, cx: &mut ViewContext<Self>) {
+        let max_lines = DockerPanelSettings::get_global(cx).max_copied_log_lines;
+        let start = self.log_lines.len().saturating_sub(max_lines);
+        let mut text = String::new();
+        for line in self.log_lines.range(start..) {
+            text.push_str(line);
+            text.push('\n');
+        }
+        cx.write_to_clipboard(ClipboardItem::new(text));
+
+        self.logs_copied_task = Some(cx.spawn(|this, mut cx| async move {
+            cx.background_executor()
+                .timer(COPIED_CONFIRMATION_DURATION)
+                .await;
+            this.update(&mut cx, |this, cx| {
+                this.logs_copied_task = None;
+                cx.notify();
+            })
+            .ok();
+        }));
+        cx.notify();
+    }
+
+    /// Writes the buffered log lines to a file chosen by the user. The platform's
+    /// save prompt can't suggest a file name, so choosing a directory writes
+    /// `<container-name>-<timestamp>.log` inside it.
+    fn export_logs(&mut self, cx: &mut ViewContext<Self>) {
+        let PanelView::Logs { container_id } = &self.view else {
+            return;
+        };
+        let Some(workspace) = self.workspace.upgrade() else {
+            return;
+        };
+
+        let now = OffsetDateTime::now_utc().to_offset(self.local_timezone);
+        let file_name = format!(
+            "{}-{:04}{:02}{:02}-{:02}{:02}{:02}.log",
+            self.container_name(container_id),
+            now.year(),
+            u8::from(now.month()),
+            now.day(),
+            now.hour(),
+            now.minute(),
+            now.second()
+        );
+        let directory = workspace_roots(workspace.read(cx).project(), cx)
+            .first()
+            .map_or_else(|| paths::HOME.clone(), |root| root.to_path_buf());
+        let path = cx.prompt_for_new_path(&directory);
+        let lines = self.log_lines.clone();
+        let fs = self.fs.clone();
+        cx.spawn(|this, mut cx| async move {
+            let Some(mut path) = path.await.ok().flatten() else {
+                return;
+            };
+            if fs.is_dir(&path).await {
+                path.push(file_name);
+            }
+
+            let result = cx
+                .background_executor()
+                .spawn({
+                    let path = path.clone();
+                    async move {
+                        let mut text = String::new();
+                        for line in &lines {
+                            text.push_str(line);
+                            text.push('\n');
+                        }
+                        fs.atomic_write(path, text).await
+                    }
+                })
+                .await;
+            this.update(&mut cx, |this, cx| match result {
+                Ok(()) => this.show_toast(format!("Exported logs to {}", path.display()), cx),
+                Err(error) => {
+                    log::error!("failed to export logs to {path:?}: {error:?}");
+                    this.show_toast(format!("Failed to export logs: {error}"), cx)
+                }
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    fn open_inspect(&mut self, container_id: String, cx: &mut ViewContext<Self>) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+
+        self.close_detail_view(cx);
+        self.view = PanelView::Inspect {
+            container_id: container_id.clone(),
+        };
+        self.inspect_task = cx.spawn(|this, mut cx| async move {
+            let output = docker.inspect_container(&container_id).await;
+            this.update(&mut cx, |this, cx| {
+                this.inspect_output = Some(match output {
+                    Ok(json) => Ok(json.into()),
+                    Err(error) => {
+                        log::error!("failed to inspect {container_id}: {error:?}");
+                        Err(format!("Failed to inspect the container: {error}").into())
+                    }
+                });
+                cx.notify();
+            })
+            .ok();
+        });
+        cx.notify();
+    }
+
+    /// Returns to the container list, which stops following the logs, drops the
+    /// cached inspect output and cancels a running build.
+    fn close_detail_view(&mut self, cx: &mut ViewContext<Self>) {
+        if self.view == PanelView::List {
+            return;
+        }
+
+        self.view = PanelView::List;
+        self.logs_task = Task::ready(());
+        self.logs_copied_task = None;
+        self.log_lines.clear();
+        self.log_list.reset(0);
+        self.inspect_task = Task::ready(());
+        self.inspect_output = None;
+        self.build = None;
+        self.rebuild = None;
+        // Commands still running keep their output, for the banner to link to.
+        if self
+            .dev_container_commands
+            .as_ref()
+            .is_some_and(|commands| commands.task.is_none())
+        {
+            self.dev_container_commands = None;
+        }
+        cx.notify();
+    }
+
+    fn run_compose_command(
+        &mut self,
+        project: ComposeProject,
+        command: ComposeCommand,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if self.compose_tasks.contains_key(&project.name) {
+            return;
+        }
+
+        let docker_host = self.active_docker_host(cx);
+        let name = project.name.clone();
+        let task = cx.spawn(|this, mut cx| async move {
+            let result = cx
+                .background_executor()
+                .spawn({
+                    let project = project.clone();
+                    async move { project.run(&docker_host, command).await }
+                })
+                .await;
+            this.update(&mut cx, |this, cx| {
+                this.compose_tasks.remove(&project.name);
+                if let Err(error) = result {
+                    let verb = match command {
+                        ComposeCommand::Up => "start",
+                        ComposeCommand::Down => "stop",
+                    };
+                    this.show_toast(
+                        format!("Failed to {verb} Compose project {}: {error}", project.name),
+                        cx,
+                    );
+                }
+                this.fetch_containers(cx);
+                cx.notify();
+            })
+            .ok();
+        });
+        self.compose_tasks.insert(name, task);
+        cx.notify();
+    }
+
+    /// Choosing the current sort key again flips the direction.
+    fn set_sort_key(&mut self, key: SortKey, cx: &mut ViewContext<Self>) {
+        if self.sort.key == key {
+            self.sort.descending = !self.sort.descending;
+        } else {
+            self.sort = SortBy {
+                key,
+                descending: key.descending_by_default(),
+            };
+        }
+        self.update_entries(cx);
+        self.serialize(cx);
+    }
+
+    fn toggle_sort_direction(&mut self, cx: &mut ViewContext<Self>) {
+        self.sort.descending = !self.sort.descending;
+        self.update_entries(cx);
+        self.serialize(cx);
+    }
+
+    fn toggle_group(&mut self, group: String, cx: &mut ViewContext<Self>) {
+        if !self.collapsed_groups.remove(&group) {
+            self.collapsed_groups.insert(group);
+        }
+        self.update_entries(cx);
+    }
+
+    /// Recomputes the rows of the container list: the containers matching the
+    /// filter, in collapsible sections by Compose project, or as a flat list when
+    /// none of them belong to one.
+    fn update_entries(&mut self, cx: &mut ViewContext<Self>) {
+        let containers = &self.containers;
+        self.selected
+            .retain(|id| containers.iter().any(|container| &container.id == id));
+        if self
+            .selected_container
+            .as_ref()
+            .is_some_and(|id| !containers.iter().any(|container| &container.id == id))
+        {
+            self.selected_container = None;
+        }
+
+        let query = self.filter.trim().to_lowercase();
+        let mut containers = self
+            .containers
+            .iter()
+            .filter(|container| container.matches(&query))
+            .collect::<Vec<_>>();
+        let sort = self.sort;
+        containers.sort_by(|a, b| {
+            // Surface unhealthy containers regardless of the order, so they're noticed.
+            let a_healthy = a.health != Some(HealthStatus::Unhealthy);
+            let b_healthy = b.health != Some(HealthStatus::Unhealthy);
+            a_healthy.cmp(&b_healthy).then_with(|| sort.compare(a, b))
+        });
+
+        let old_entries = std::mem::take(&mut self.list_entries);
+        if containers
+            .iter()
+            .all(|container| container.compose.is_none())
+        {
+            self.list_entries
+                .extend(
+                    containers
+                        .into_iter()
+                        .map(|container| ListEntry::Container {
+                            container_id: container.id.clone(),
+                        }),
+                );
+        } else {
+            let mut groups = BTreeMap::<&str, Vec<&ContainerEntry>>::default();
+            let mut standalone = Vec::new();
+            for container in containers {
+                match &container.compose {
+                    Some(project) => groups
+                        .entry(project.name.as_str())
+                        .or_default()
+                        .push(container),
+                    None => standalone.push(container),
+                }
+            }
+
+            let groups = groups
+                .into_iter()
+                .chain((!standalone.is_empty()).then_some((STANDALONE_GROUP, standalone)));
+            for (group, containers) in groups {
+                self.list_entries.push(ListEntry::GroupHeader {
+                    group: group.to_string(),
+                    project: containers
+                        .first()
+                        .and_then(|container| container.compose.clone()),
+                    count: containers.len(),
+                });
+                if !self.collapsed_groups.contains(group) {
+                    self.list_entries
+                        .extend(
+                            containers
+                                .into_iter()
+                                .map(|container| ListEntry::Container {
+                                    container_id: container.id.clone(),
+                                }),
+                        );
+                }
+            }
+        }
+
+        // Keep the row that was at the top of the list in place, so refreshing
+        // doesn't scroll the list.
+        let old_scroll_top = self.container_list.logical_scroll_top();
+        self.container_list.reset(self.list_entries.len());
+        if let Some(old_top_entry) = old_entries.get(old_scroll_top.item_ix) {
+            let new_scroll_top = self
+                .list_entries
+                .iter()
+                .position(|entry| entry.key() == old_top_entry.key())
+                .map(|item_ix| ListOffset {
+                    item_ix,
+                    offset_in_item: old_scroll_top.offset_in_item,
+                });
+            self.container_list
+                .scroll_to(new_scroll_top.unwrap_or(old_scroll_top));
+        }
+        cx.notify();
+    }
+
+    fn show_toast(&mut self, message: impl Into<Cow<'static, str>>, cx: &mut ViewContext<Self>) {
+        let id = NotificationId::unique::<DockerToast>();
+        let message = message.into();
+        self.workspace
+            .update(cx, |workspace, cx| {
+                workspace.show_toast(Toast::new(id.clone(), message), cx)
+            })
+            .ok();
+        self.dismiss_toast_later(id, cx);
+    }
+
+    /// Like [`Self::show_toast`], but draws attention to something having gone wrong.
+    fn show_warning(&mut self, message: impl Into<SharedString>, cx: &mut ViewContext<Self>) {
+        let id = NotificationId::unique::<DockerToast>();
+        let message = message.into();
+        self.workspace
+            .update(cx, |workspace, cx| {
+                workspace.show_notification(id.clone(), cx, |cx| {
+                    cx.new_view(|_| WarningNotification { message })
+                })
+            })
+            .ok();
+        self.dismiss_toast_later(id, cx);
+    }
+
+    /// Toasts stay up until dismissed, which is too long for the panel's updates.
+    fn dismiss_toast_later(&mut self, id: NotificationId, cx: &mut ViewContext<Self>) {
+        let workspace = self.workspace.clone();
+        self.toast_dismissal = cx.spawn(|_, mut cx| async move {
+            cx.background_executor().timer(TOAST_DURATION).await;
+            workspace
+                .update(&mut cx, |workspace, cx| workspace.dismiss_toast(&id, cx))
+                .ok();
+        });
+    }
+
+    fn render_lifecycle_buttons(
+        &self,
+        container: &ContainerEntry,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let in_flight = self.lifecycle_tasks.contains_key(&container.id);
+        let button = |id: &str, icon: IconName, tooltip: &'static str, action: ContainerAction| {
+            let container_id = container.id.clone();
+            IconButton::new(SharedString::from(format!("{id}-{}", container.id)), icon)
+                .icon_size(IconSize::Small)
+                .disabled(in_flight)
+                .tooltip(move |cx| Tooltip::text(tooltip, cx))
+                .on_click(cx.listener(move |this, _, cx| {
+                    this.run_container_action(container_id.clone(), action, cx)
+                }))
+        };
+
+        h_flex()
+            .flex_none()
+            .gap_1()
+            .map(|this| {
+                if container.is_running() {
+                    let container_id = container.id.clone();
+                    this.child(
+                        IconButton::new(
+                            SharedString::from(format!("shell-{}", container.id)),
+                            IconName::Terminal,
+                        )
+                        .icon_size(IconSize::Small)
+                        .tooltip(|cx| Tooltip::text("Open Shell", cx))
+                        .on_click(
+                            cx.listener(move |this, _, cx| {
+                                this.open_shell(container_id.clone(), cx)
+                            }),
+                        ),
+                    )
+                    .child(button(
+                        "pause",
+                        IconName::Pause,
+                        "Pause",
+                        ContainerAction::Pause,
+                    ))
+                    .child(button(
+                        "restart",
+                        IconName::RotateCw,
+                        "Restart",
+                        ContainerAction::Restart,
+                    ))
+                    .child(button(
+                        "stop",
+                        IconName::Stop,
+                        "Stop",
+                        ContainerAction::Stop,
+                    ))
+                } else if container.is_paused() {
+                    this.child(button(
+                        "unpause",
+                        IconName::Play,
+                        "Unpause",
+                        ContainerAction::Unpause,
+                    ))
+                    .child(button(
+                        "stop",
+                        IconName::Stop,
+                        "Stop",
+                        ContainerAction::Stop,
+                    ))
+                } else {
+                    this.child(button(
+                        "start",
+                        IconName::Play,
+                        "Start",
+                        ContainerAction::Start,
+                    ))
+                }
+            })
+            .child({
+                let container_id = container.id.clone();
+                IconButton::new(
+                    SharedString::from(format!("remove-{}", container.id)),
+                    IconName::Trash,
+                )
+                .icon_size(IconSize::Small)
+                .disabled(in_flight)
+                .tooltip(|cx| Tooltip::text("Remove", cx))
+                .on_click(
+                    cx.listener(move |this, _, cx| this.request_removal(container_id.clone(), cx)),
+                )
+            })
+    }
+
+    fn render_removal_prompt(
+        &self,
+        container: &ContainerEntry,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        // Docker refuses to remove running or paused containers unless they're
+        // forcibly killed.
+        let force = container.is_running() || container.is_paused();
+
+        h_flex()
+            .flex_none()
+            .gap_1()
+            .child(
+                Label::new(if force { "Force remove?" } else { "Remove?" })
+                    .size(LabelSize::Small)
+                    .color(Color::Warning),
+            )
+            .child(
+                Button::new("confirm_removal", "Yes")
+                    .label_size(LabelSize::Small)
+                    .style(ButtonStyle::Filled)
+                    .on_click(cx.listener(move |this, _, cx| this.confirm_removal(force, cx))),
+            )
+            .child(
+                Button::new("cancel_removal", "No")
+                    .label_size(LabelSize::Small)
+                    .on_click(cx.listener(|this, _, cx| this.cancel_removal(cx))),
+            )
+    }
+
+    fn render_list_entry(&mut self, ix: usize, cx: &mut ViewContext<Self>) -> Option<AnyElement> {
+        match self.list_entries.get(ix)? {
+            ListEntry::GroupHeader {
+                group,
+                project,
+                count,
+            } => {
+                let collapsed = self.collapsed_groups.contains(group);
+                Some(
+                    self.render_group_header(group, project.as_ref(), *count, collapsed, cx)
+                        .into_any_element(),
+                )
+            }
+            ListEntry::Container { container_id } => {
+                let container = self
+                    .containers
+                    .iter()
+                    .find(|container| &container.id == container_id)?;
+                Some(self.render_container(container, cx).into_any_element())
+            }
+        }
+    }
+
+    fn render_group_header(
+        &self,
+        group: &str,
+        project: Option<&ComposeProject>,
+        count: usize,
+        collapsed: bool,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let group = group.to_string();
+        h_flex()
+            .id(SharedString::from(format!("group-{group}")))
+            .w_full()
+            .px_2()
+            .py_1()
+            .gap_1()
+            .cursor_pointer()
+            .hover(|style| style.bg(cx.theme().colors().element_hover))
+            .child(
+                Icon::new(if collapsed {
+                    IconName::ChevronRight
+                } else {
+                    IconName::ChevronDown
+                })
+                .size(IconSize::Small)
+                .color(Color::Muted),
+            )
+            .child(Label::new(group.clone()).size(LabelSize::Small))
+            .child(
+                Label::new(count.to_string())
+                    .size(LabelSize::Small)
+                    .color(Color::Muted),
+            )
+            .children(project.map(|project| {
+                h_flex().ml_auto().gap_1().map(|this| {
+                    if self.compose_tasks.contains_key(&project.name) {
+                        this.child(
+                            Icon::new(IconName::ArrowCircle)
+                                .size(IconSize::Small)
+                                .color(Color::Muted)
+                                .with_animation(
+                                    SharedString::from(format!("compose-{}", project.name)),
+                                    Animation::new(Duration::from_secs(2)).repeat(),
+                                    |icon, delta| {
+                                        icon.transform(Transformation::rotate(percentage(delta)))
+                                    },
+                                ),
+                        )
+                    } else {
+                        let compose_button = |label: &'static str, command| {
+                            let project = project.clone();
+                            Button::new(
+                                SharedString::from(format!("compose-{label}-{}", project.name)),
+                                label,
+                            )
+                            .label_size(LabelSize::Small)
+                            .tooltip(move |cx| {
+                                Tooltip::text(
+                                    match command {
+                                        ComposeCommand::Up => "docker compose up -d",
+                                        ComposeCommand::Down => "docker compose down",
+                                    },
+                                    cx,
+                                )
+                            })
+                            .on_click(cx.listener(
+                                move |this, _, cx| {
+                                    this.run_compose_command(project.clone(), command, cx)
+                                },
+                            ))
+                        };
+                        this.child(compose_button("Up", ComposeCommand::Up))
+                            .child(compose_button("Down", ComposeCommand::Down))
+                    }
+                })
+            }))
+            .on_click(cx.listener(move |this, _, cx| this.toggle_group(group.clone(), cx)))
+    }
+
+    fn render_created(&self, id: SharedString, created: OffsetDateTime) -> impl IntoElement {
+        let now = OffsetDateTime::now_utc();
+        // The daemon's clock may be ahead of ours, which would otherwise read as
+        // being created in the future.
+        let created = created.min(now);
+        let relative = time_format::format_localized_timestamp(
+            created,
+            now,
+            self.local_timezone,
+            time_format::TimestampFormat::Relative,
+        );
+        let absolute: SharedString = time_format::format_localized_timestamp(
+            created,
+            now,
+            self.local_timezone,
+            time_format::TimestampFormat::EnhancedAbsolute,
+        )
+        .into();
+
+        div()
+            .id(SharedString::from(format!("created-{id}")))
+            .child(
+                Label::new(format!("Created {}", relative.to_lowercase()))
+                    .size(LabelSize::XSmall)
+                    .color(Color::Muted),
+            )
+            .tooltip(move |cx| Tooltip::text(absolute.clone(), cx))
+    }
+
+    fn render_health_badge(
+        &self,
+        container: &ContainerEntry,
+        health: HealthStatus,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let (color, border_color) = match health {
+            HealthStatus::Starting => (Color::Muted, cx.theme().colors().border),
+            HealthStatus::Healthy => (Color::Success, cx.theme().status().success_border),
+            HealthStatus::Unhealthy => (Color::Error, cx.theme().status().error_border),
+        };
+        let tooltip: SharedString = container
+            .health_check_output
+            .clone()
+            .unwrap_or_else(|| "No health check output".into());
+
+        div()
+            .id(SharedString::from(format!("health-{}", container.id)))
+            .px_1()
+            .rounded_md()
+            .border_1()
+            .border_color(border_color)
+            .child(
+                Label::new(health.label())
+                    .size(LabelSize::XSmall)
+                    .color(color),
+            )
+            .tooltip(move |cx| Tooltip::text(tooltip.clone(), cx))
+    }
+
+    fn render_ports(&self, container: &ContainerEntry) -> impl IntoElement {
+        h_flex()
+            .flex_wrap()
+            .gap_1()
+            .children(container.ports.iter().enumerate().map(|(ix, port)| {
+                let label = port.label();
+                match port.url() {
+                    Some(url) => Button::new(("port", ix), label)
+                        .label_size(LabelSize::XSmall)
+                        .style(ButtonStyle::Subtle)
+                        .tooltip({
+                            let url = url.clone();
+                            move |cx| Tooltip::text(format!("Open {url}"), cx)
+                        })
+                        .on_click(move |_, cx| cx.open_url(&url))
+                        .into_any_element(),
+                    None => Label::new(label)
+                        .size(LabelSize::XSmall)
+                        .color(Color::Muted)
+                        .into_any_element(),
+                }
+            }))
+    }
+
+    fn render_container(
+        &self,
+        container: &ContainerEntry,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let container_id = container.id.clone();
+        h_flex()
+            .id(SharedString::from(container.id.clone()))
+            .w_full()
+            .px_2()
+            .py_1()
+            .gap_2()
+            .justify_between()
+            .cursor_pointer()
+            .when(
+                self.selected_container.as_ref() == Some(&container.id),
+                |this| this.bg(cx.theme().colors().element_selected),
+            )
+            .hover(|style| style.bg(cx.theme().colors().element_hover))
+            .on_click(cx.listener({
+                let container_id = container_id.clone();
+                move |this, _, cx| {
+                    this.selected_container = Some(container_id.clone());
+                    if this.selecting {
+                        this.toggle_selected(container_id.clone(), cx)
+                    } else {
+                        this.open_logs(container_id.clone(), cx)
+                    }
+                }
+            }))
+            .on_secondary_mouse_down(cx.listener(move |this, event: &MouseDownEvent, cx| {
+                this.deploy_container_context_menu(event.position, container_id.clone(), cx)
+            }))
+            .when(self.selecting, |this| {
+                // Clicking anywhere on the row toggles the checkbox.
+                this.child(Checkbox::new(
+                    SharedString::from(format!("select-{}", container.id)),
+                    if self.selected.contains(&container.id) {
+                        Selection::Selected
+                    } else {
+                        Selection::Unselected
+                    },
+                ))
+            })
+            .child(
+                v_flex()
+                    .flex_1()
+                    .overflow_hidden()
+                    .child(
+                        h_flex()
+                            .gap_1()
+                            .map(|this| match &self.renaming {
+                                Some(rename) if rename.container_id == container.id => this.child(
+                                    div()
+                                        .flex_1()
+                                        .on_mouse_down(MouseButton::Left, |_, cx| {
+                                            // Don't open the logs when placing the cursor.
+                                            cx.stop_propagation()
+                                        })
+                                        .on_action(cx.listener(|this, _: &menu::Confirm, cx| {
+                                            this.confirm_rename(cx)
+                                        }))
+                                        .on_action(cx.listener(
+                                            |this, _: &editor::actions::Cancel, cx| {
+                                                this.cancel_rename(cx)
+                                            },
+                                        ))
+                                        .child(rename.editor.clone()),
+                                ),
+                                _ => this.child(Label::new(container.name.clone())),
+                            })
+                            .when(container.is_paused(), |this| {
+                                this.child(
+                                    div()
+                                        .px_1()
+                                        .rounded_md()
+                                        .border_1()
+                                        .border_color(cx.theme().status().warning_border)
+                                        .child(
+                                            Label::new("Paused")
+                                                .size(LabelSize::XSmall)
+                                                .color(Color::Warning),
+                                        ),
+                                )
+                            }),
+                    )
+                    .children(
+                        self.renaming
+                            .as_ref()
+                            .filter(|rename| rename.container_id == container.id)
+                            .and_then(|rename| rename.error.clone())
+                            .map(|error| {
+                                Label::new(error)
+                                    .size(LabelSize::XSmall)
+                                    .color(Color::Error)
+                            }),
+                    )
+                    .child(
+                        Label::new(container.image.clone())
+                            .size(LabelSize::Small)
+                            .color(Color::Muted),
+                    )
+                    .when(
+                        container.is_running() && !container.ports.is_empty(),
+                        |this| this.child(self.render_ports(container)),
+                    ),
+            )
+            .child(
+                v_flex()
+                    .items_end()
+                    .flex_none()
+                    .child(
+                        h_flex()
+                            .gap_1()
+                            .children(
+                                container
+                                    .health
+                                    .map(|health| self.render_health_badge(container, health, cx)),
+                            )
+                            .child(
+                                Label::new(container.status.clone())
+                                    .size(LabelSize::Small)
+                                    .color(match container.state {
+                                        ContainerState::Running => Color::Success,
+                                        ContainerState::Paused => Color::Warning,
+                                        _ => Color::Muted,
+                                    }),
+                            ),
+                    )
+                    .child(self.render_created(container.id.clone().into(), container.created))
+                    .children(container.stats.map(|stats| {
+                        Label::new(format!(
+                            "{:.1}% · {} / {}",
+                            stats.cpu_percent,
+                            format_bytes(stats.memory_usage),
+                            format_bytes(stats.memory_limit)
+                        ))
+                        .size(LabelSize::Small)
+                        .color(Color::Muted)
+                    })),
+            )
+            .map(|this| {
+                if self.confirming_removal.as_ref() == Some(&container.id) {
+                    this.child(self.render_removal_prompt(container, cx))
+                } else {
+                    this.child(self.render_lifecycle_buttons(container, cx))
+                }
+            })
+    }
+}
+
+/// Resolves the Docker host from the settings, falling back to `DOCKER_HOST`
+/// and then the platform default, like the Docker CLI does.
+fn docker_host(cx: &AppContext) -> String {
+    DockerPanelSettings::get_global(cx)
+        .docker_host
+        .clone()
+        .or_else(|| std::env::var("DOCKER_HOST").ok())
+        .unwrap_or_else(|| DEFAULT_DOCKER_HOST.to_string())
+}
+
+/// Explains the pull failures that users are likely to run into.
+fn pull_error_message(reference: &str, error: &anyhow::Error) -> String {
+    let message = error.to_string();
+    let lowercase = message.to_lowercase();
+    if lowercase.contains("invalid reference format") {
+        format!("{reference} is not a valid image reference")
+    } else if lowercase.contains("unauthorized")
+        || lowercase.contains("authentication required")
+        || lowercase.contains("pull access denied")
+    {
+        format!(
+            "Failed to pull {reference}: it doesn't exist, or the registry requires you to \
+            log in with `docker login`"
+        )
+    } else {
+        format!("Failed to pull {reference}: {message}")
+    }
+}
+
+/// The absolute paths of the project's local, visible worktrees.
+fn workspace_roots(project: &Model<Project>, cx: &AppContext) -> Vec<Arc<Path>> {
+    project
+        .read(cx)
+        .visible_worktrees(cx)
+        .filter_map(|worktree| Some(worktree.read(cx).as_local()?.abs_path().clone()))
+        .collect()
+}
+
+/// Formats a byte count using binary units, e.g. `256 MiB`.
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024. && unit < UNITS.len() - 1 {
+        value /= 1024.;
+        unit += 1;
+    }
+
+    if unit == 0 || value.fract() < 0.05 || value >= 100. {
+        format!("{:.0} {}", value, UNITS[unit])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+impl DockerPanel {
+    fn render_connection_status(&self) -> impl IntoElement {
+        let (color, tooltip): (_, SharedString) = match &self.connection {
+            ConnectionState::Connecting => (Color::Muted, "Connecting to Docker…".into()),
+            ConnectionState::Connected => (Color::Success, "Connected to Docker".into()),
+            ConnectionState::Disconnected { error } => (Color::Error, error.clone()),
+        };
+
+        div()
+            .id("connection_status")
+            .p_1()
+            .child(Indicator::dot().color(color))
+            .tooltip(move |cx| Tooltip::text(tooltip.clone(), cx))
+    }
+
+    /// Only shown when there's more than one context to choose from.
+    fn render_context_picker(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        if self.docker_contexts.len() <= 1 {
+            return None;
+        }
+
+        let this = cx.view().clone();
+        let active_context = self
+            .docker_context
+            .clone()
+            .unwrap_or_else(|| DEFAULT_CONTEXT.into());
+        let context_names = self
+            .docker_contexts
+            .iter()
+            .map(|context| context.name.clone())
+            .collect::<Vec<_>>();
+        Some(
+            popover_menu("context_picker")
+                .trigger(
+                    Button::new("active_context", active_context.clone())
+                        .label_size(LabelSize::Small)
+                        .style(ButtonStyle::Subtle)
+                        .tooltip(|cx| Tooltip::text("Docker Context", cx)),
+                )
+                .menu(move |cx| {
+                    Some(ContextMenu::build(cx, |mut menu, cx| {
+                        for name in &context_names {
+                            menu = menu.toggleable_entry(
+                                name.clone(),
+                                *name == active_context,
+                                None,
+                                cx.handler_for(&this, {
+                                    let name = name.clone();
+                                    move |this, cx| this.set_docker_context(name.clone(), cx)
+                                }),
+                            );
+                        }
+                        menu
+                    }))
+                }),
+        )
+    }
+
+    fn render_dev_container_banner(
+        &self,
+        dev_container: &DevContainer,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let forward_ports = &dev_container.config.forward_ports;
+        let reopening = self.reopen_in_container_task.is_some();
+        let rebuilding = self.is_rebuilding_dev_container();
+        let running = self.running_dev_container().is_some();
+
+        h_flex()
+            .px_2()
+            .py_1()
+            .gap_2()
+            .justify_between()
+            .bg(cx.theme().colors().surface_background)
+            .border_b_1()
+            .border_color(cx.theme().colors().border_variant)
+            .child(
+                h_flex()
+                    .gap_2()
+                    .overflow_hidden()
+                    .child(
+                        Icon::new(IconName::FolderOpen)
+                            .size(IconSize::Small)
+                            .color(Color::Muted),
+                    )
+                    .child(
+                        v_flex()
+                            .overflow_hidden()
+                            .child(Label::new(dev_container.name()).size(LabelSize::Small))
+                            .when(!forward_ports.is_empty(), |this| {
+                                let ports = forward_ports
+                                    .iter()
+                                    .map(ToString::to_string)
+                                    .collect::<Vec<_>>()
+                                    .join(", ");
+                                this.child(
+                                    Label::new(format!("Forwards {ports}"))
+                                        .size(LabelSize::XSmall)
+                                        .color(Color::Muted),
+                                )
+                            })
+                            .children(self.running_dev_container_command().map(|property| {
+                                Button::new(
+                                    "show_dev_container_commands",
+                                    format!("Running {property}…"),
+                                )
+                                .label_size(LabelSize::XSmall)
+                                .color(Color::Muted)
+                                .icon(IconName::ArrowCircle)
+                                .icon_position(IconPosition::Start)
+                                .icon_size(IconSize::XSmall)
+                                .icon_color(Color::Muted)
+                                .tooltip(|cx| Tooltip::text("Show Output", cx))
+                                .on_click(
+                                    cx.listener(|this, _, cx| this.show_dev_container_commands(cx)),
+                                )
+                            })),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .gap_1()
+                    .flex_none()
+                    .child(
+                        IconButton::new("rebuild_dev_container", IconName::RotateCw)
+                            .icon_size(IconSize::Small)
+                            .disabled(reopening || rebuilding || self.docker.is_none())
+                            .tooltip(|cx| Tooltip::text("Rebuild Container", cx))
+                            .on_click(cx.listener(|this, _, cx| this.rebuild_dev_container(cx))),
+                    )
+                    .child(
+                        Button::new(
+                            "reopen_in_container",
+                            if running {
+                                "Running in Container"
+                            } else {
+                                "Reopen in Container"
+                            },
+                        )
+                        .label_size(LabelSize::Small)
+                        .style(ButtonStyle::Filled)
+                        .disabled(reopening || rebuilding || running || self.docker.is_none())
+                        .when(reopening, |this| {
+                            this.icon(IconName::ArrowCircle)
+                                .icon_position(IconPosition::Start)
+                                .icon_size(IconSize::Small)
+                                .icon_color(Color::Muted)
+                        })
+                        .tooltip({
+                            let config_path: SharedString = dev_container
+                                .config_path
+                                .to_string_lossy()
+                                .into_owned()
+                                .into();
+                            move |cx| Tooltip::text(config_path.clone(), cx)
+                        })
+                        .on_click(cx.listener(|this, _, cx| this.reopen_in_container(cx))),
+                    ),
+            )
+    }
+
+    fn render_port_forwards(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let column = |label: String| {
+            div()
+                .w(rems(6.))
+                .child(Label::new(label).size(LabelSize::Small))
+        };
+        v_flex()
+            .px_2()
+            .py_1()
+            .border_b_1()
+            .border_color(cx.theme().colors().border_variant)
+            .child(
+                h_flex()
+                    .child(
+                        div().w(rems(6.)).child(
+                            Label::new("Host")
+                                .size(LabelSize::XSmall)
+                                .color(Color::Muted),
+                        ),
+                    )
+                    .child(
+                        div().w(rems(6.)).child(
+                            Label::new("Container")
+                                .size(LabelSize::XSmall)
+                                .color(Color::Muted),
+                        ),
+                    ),
+            )
+            .children(self.port_forwards.iter().map(|forward| {
+                let container_port = forward.container_port;
+                let host = match (&forward.host_port, &forward.error) {
+                    (Some(host_port), _) => format!("localhost:{host_port}"),
+                    (None, Some(_)) => "Failed".to_string(),
+                    (None, None) if forward.task.is_some() => "Forwarding…".to_string(),
+                    (None, None) => "—".to_string(),
+                };
+                h_flex()
+                    .gap_1()
+                    .child(column(host))
+                    .child(column(container_port.to_string()))
+                    .child(h_flex().flex_1().justify_end().gap_1().map(|this| {
+                        if let Some(host_port) = forward.host_port {
+                            let url = format!("http://localhost:{host_port}");
+                            this.child(
+                                Button::new(
+                                    ("open_forwarded_port", container_port as usize),
+                                    "Open",
+                                )
+                                .label_size(LabelSize::XSmall)
+                                .style(ButtonStyle::Subtle)
+                                .tooltip({
+                                    let url = url.clone();
+                                    move |cx| Tooltip::text(format!("Open {url}"), cx)
+                                })
+                                .on_click(move |_, cx| cx.open_url(&url)),
+                            )
+                            .when(!forward.published, |this| {
+                                this.child(
+                                    IconButton::new(
+                                        ("remove_port_forward", container_port as usize),
+                                        IconName::Close,
+                                    )
+                                    .icon_size(IconSize::Small)
+                                    .tooltip(|cx| Tooltip::text("Stop Forwarding", cx))
+                                    .on_click(cx.listener(
+                                        move |this, _, cx| {
+                                            this.remove_port_forward(container_port, cx)
+                                        },
+                                    )),
+                                )
+                            })
+                        } else if forward.is_removed() || forward.error.is_some() {
+                            this.child(
+                                Button::new(("forward_port", container_port as usize), "Forward")
+                                    .label_size(LabelSize::XSmall)
+                                    .style(ButtonStyle::Subtle)
+                                    .when_some(forward.error.clone(), |this, error| {
+                                        this.tooltip(move |cx| Tooltip::text(error.clone(), cx))
+                                    })
+                                    .on_click(cx.listener(move |this, _, cx| {
+                                        this.forward_port(container_port, cx)
+                                    })),
+                            )
+                        } else {
+                            this
+                        }
+                    }))
+            }))
+    }
+
+    fn render_connecting(&self) -> impl IntoElement {
+        h_flex()
+            .p_4()
+            .gap_2()
+            .justify_center()
+            .child(
+                Icon::new(IconName::ArrowCircle)
+                    .size(IconSize::Small)
+                    .color(Color::Muted)
+                    .with_animation(
+                        "connecting",
+                        Animation::new(Duration::from_secs(2)).repeat(),
+                        |icon, delta| icon.transform(Transformation::rotate(percentage(delta))),
+                    ),
+            )
+            .child(
+                Label::new("Connecting to Docker…")
+                    .color(Color::Muted)
+                    .size(LabelSize::Small),
+            )
+    }
+
+    fn render_disconnected(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let last_attempt = self.last_connection_attempt.map(|timestamp| {
+            time_format::format_localized_timestamp(
+                timestamp,
+                OffsetDateTime::now_utc(),
+                self.local_timezone,
+                time_format::TimestampFormat::EnhancedAbsolute,
+            )
+        });
+
+        v_flex()
+            .p_4()
+            .gap_2()
+            .items_center()
+            .child(
+                Label::new("Cannot find running Docker instance.")
+                    .color(Color::Muted)
+                    .size(LabelSize::Small),
+            )
+            .children(last_attempt.map(|last_attempt| {
+                Label::new(format!("Last attempted {last_attempt}"))
+                    .color(Color::Muted)
+                    .size(LabelSize::XSmall)
+            }))
+            .child(
+                Button::new("retry_connection", "Retry")
+                    .icon(IconName::ArrowCircle)
+                    .icon_position(IconPosition::Start)
+                    .icon_size(IconSize::Small)
+                    .icon_color(Color::Muted)
+                    .style(ButtonStyle::Filled)
+                    .on_click(cx.listener(|this, _, cx| this.retry_connection(cx))),
+            )
+    }
+
+    fn render_log_line(&mut self, ix: usize, _: &mut ViewContext<Self>) -> Option<AnyElement> {
+        let line = self.log_lines.get(ix)?;
+        Some(
+            div()
+                .px_2()
+                .text_xs()
+                .whitespace_nowrap()
+                .child(line.clone())
+                .into_any(),
+        )
+    }
+
+    fn render_rebuild_output_line(
+        &mut self,
+        ix: usize,
+        _: &mut ViewContext<Self>,
+    ) -> Option<AnyElement> {
+        let line = self.rebuild.as_ref()?.output.get(ix)?;
+        Some(
+            div()
+                .px_2()
+                .text_xs()
+                .whitespace_nowrap()
+                .child(line.clone())
+                .into_any(),
+        )
+    }
+
+    fn render_rebuild_view(
+        &self,
+        rebuild: &RebuildDevContainer,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let buffer_font = ThemeSettings::get_global(cx).buffer_font.family.clone();
+        v_flex()
+            .size_full()
+            .child(
+                self.render_detail_header(format!("Rebuild: {}", rebuild.name), cx)
+                    .when(rebuild.task.is_some(), |this| {
+                        this.child(
+                            Button::new("cancel_rebuild", "Cancel")
+                                .label_size(LabelSize::Small)
+                                .style(ButtonStyle::Subtle)
+                                .on_click(cx.listener(|this, _, cx| this.cancel_rebuild(cx))),
+                        )
+                    }),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .size_full()
+                    .font_family(buffer_font)
+                    .child(list(rebuild.output_list.clone()).size_full()),
+            )
+    }
+
+    fn render_dev_container_command_output_line(
+        &mut self,
+        ix: usize,
+        _: &mut ViewContext<Self>,
+    ) -> Option<AnyElement> {
+        let line = self.dev_container_commands.as_ref()?.output.get(ix)?;
+        Some(
+            div()
+                .px_2()
+                .text_xs()
+                .whitespace_nowrap()
+                .child(line.clone())
+                .into_any(),
+        )
+    }
+
+    fn render_dev_container_commands_view(
+        &self,
+        commands: &DevContainerCommands,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let buffer_font = ThemeSettings::get_global(cx).buffer_font.family.clone();
+        v_flex()
+            .size_full()
+            .child(self.render_detail_header(format!("Lifecycle Commands: {}", commands.name), cx))
+            .child(
+                v_flex()
+                    .px_2()
+                    .py_1()
+                    .gap_0p5()
+                    .border_b_1()
+                    .border_color(cx.theme().colors().border_variant)
+                    .children(commands.commands.iter().enumerate().map(|(ix, command)| {
+                        let icon = match command.status {
+                            CommandStatus::Pending => Icon::new(IconName::Dash)
+                                .color(Color::Muted)
+                                .into_any_element(),
+                            CommandStatus::Running => Icon::new(IconName::ArrowCircle)
+                                .color(Color::Muted)
+                                .with_animation(
+                                    ("dev-container-command", ix),
+                                    Animation::new(Duration::from_secs(2)).repeat(),
+                                    |icon, delta| {
+                                        icon.transform(Transformation::rotate(percentage(delta)))
+                                    },
+                                )
+                                .into_any_element(),
+                            CommandStatus::Succeeded => Icon::new(IconName::Check)
+                                .color(Color::Success)
+                                .into_any_element(),
+                            CommandStatus::Failed => Icon::new(IconName::XCircle)
+                                .color(Color::Error)
+                                .into_any_element(),
+                        };
+                        h_flex()
+                            .gap_2()
+                            .overflow_hidden()
+                            .child(icon)
+                            .child(Label::new(command.property).size(LabelSize::Small))
+                            .child(
+                                Label::new(command.command.to_string())
+                                    .size(LabelSize::Small)
+                                    .color(Color::Muted),
+                            )
+                    })),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .size_full()
+                    .font_family(buffer_font)
+                    .child(list(commands.output_list.clone()).size_full()),
+            )
+    }
+
+    fn render_build_output_line(
+        &mut self,
+        ix: usize,
+        _: &mut ViewContext<Self>,
+    ) -> Option<AnyElement> {
+        let line = self.build.as_ref()?.output.get(ix)?;
+        Some(
+            div()
+                .px_2()
+                .text_xs()
+                .whitespace_nowrap()
+                .child(line.clone())
+                .into_any(),
+        )
+    }
+
+    /// The container's name, or its short id once it's gone from the list.
+    fn container_name(&self, container_id: &str) -> String {
+        self.containers
+            .iter()
+            .find(|container| container.id == container_id)
+            .map_or_else(
+                || container_id.chars().take(12).collect(),
+                |container| container.name.clone(),
+            )
+    }
+
+    /// The header of a container's detail view, with a button back to the list.
+    fn render_detail_header(&self, title: String, cx: &mut ViewContext<Self>) -> Div {
+        h_flex()
+            .px_2()
+            .py_1()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().colors().border_variant)
+            .child(
+                IconButton::new("back_to_containers", IconName::ArrowLeft)
+                    .icon_size(IconSize::Small)
+                    .tooltip(|cx| Tooltip::text("Back to Containers", cx))
+                    .on_click(cx.listener(|this, _, cx| this.close_detail_view(cx))),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .overflow_hidden()
+                    .child(Label::new(title).size(LabelSize::Small)),
+            )
+    }
+
+    fn render_mounts(&self, mounts: &[Mount], cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .px_2()
+            .py_1()
+            .gap_0p5()
+            .border_b_1()
+            .border_color(cx.theme().colors().border_variant)
+            .child(
+                Label::new("Mounts")
+                    .size(LabelSize::XSmall)
+                    .color(Color::Muted),
+            )
+            .children(mounts.iter().map(|mount| {
+                let source = mount.name.clone().unwrap_or_else(|| mount.source.clone());
+                h_flex()
+                    .gap_2()
+                    .overflow_hidden()
+                    .child(
+                        div().w(rems(3.)).flex_none().child(
+                            Label::new(mount.kind.clone())
+                                .size(LabelSize::XSmall)
+                                .color(Color::Muted),
+                        ),
+                    )
+                    .child(
+                        Label::new(format!("{source} → {}", mount.destination))
+                            .size(LabelSize::Small),
+                    )
+            }))
+    }
+
+    fn render_inspect_view(
+        &self,
+        container_id: &str,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let name = self.container_name(container_id);
+        let buffer_font = ThemeSettings::get_global(cx).buffer_font.family.clone();
+
+        let mounts = self
+            .containers
+            .iter()
+            .find(|container| container.id == container_id)
+            .map_or(&[][..], |container| container.mounts.as_slice());
+
+        v_flex()
+            .size_full()
+            .child(self.render_detail_header(format!("Inspect: {name}"), cx))
+            .when(!mounts.is_empty(), |this| {
+                this.child(self.render_mounts(mounts, cx))
+            })
+            .map(|this| match &self.inspect_output {
+                Some(Ok(json)) => this.child(
+                    div()
+                        .id("inspect_output")
+                        .flex_1()
+                        .size_full()
+                        .overflow_scroll()
+                        .p_2()
+                        .font_family(buffer_font)
+                        .text_xs()
+                        .whitespace_nowrap()
+                        .child(json.clone()),
+                ),
+                Some(Err(error)) => this.child(
+                    v_flex().p_4().child(
+                        Label::new(error.clone())
+                            .color(Color::Error)
+                            .size(LabelSize::Small),
+                    ),
+                ),
+                None => this.child(
+                    v_flex().p_4().child(
+                        Label::new("Loading…")
+                            .color(Color::Muted)
+                            .size(LabelSize::Small),
+                    ),
+                ),
+            })
+    }
+
+    fn render_logs_view(&self, container_id: &str, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let name = self.container_name(container_id);
+        let buffer_font = ThemeSettings::get_global(cx).buffer_font.family.clone();
+
+        v_flex()
+            .size_full()
+            .child(
+                self.render_detail_header(format!("Logs: {name}"), cx)
+                    .child(
+                        Button::new(
+                            "copy_logs",
+                            if self.logs_copied_task.is_some() {
+                                "Copied"
+                            } else {
+                                "Copy"
+                            },
+                        )
+                        .icon(if self.logs_copied_task.is_some() {
+                            IconName::Check
+                        } else {
+                            IconName::Copy
+                        })
+                        .icon_position(IconPosition::Start)
+                        .icon_size(IconSize::Small)
+                        .label_size(LabelSize::Small)
+                        .style(ButtonStyle::Subtle)
+                        .disabled(self.log_lines.is_empty())
+                        .tooltip(|cx| Tooltip::text("Copy Logs to Clipboard", cx))
+                        .on_click(cx.listener(|this, _, cx| this.copy_logs(cx))),
+                    )
+                    .child(
+                        IconButton::new("export_logs", IconName::Save)
+                            .icon_size(IconSize::Small)
+                            .disabled(self.log_lines.is_empty())
+                            .tooltip(|cx| Tooltip::text("Export Logs", cx))
+                            .on_click(cx.listener(|this, _, cx| this.export_logs(cx))),
+                    )
+                    .child(
+                        Button::new("follow_logs", "Follow")
+                            .icon(IconName::ArrowDownFromLine)
+                            .icon_position(IconPosition::Start)
+                            .icon_size(IconSize::Small)
+                            .label_size(LabelSize::Small)
+                            .style(ButtonStyle::Subtle)
+                            .selected_style(ButtonStyle::Tinted(TintColor::Accent))
+                            .selected(self.follow_logs)
+                            .tooltip(|cx| Tooltip::text("Keep Scrolled to the Latest Line", cx))
+                            .on_click(cx.listener(|this, _, cx| this.toggle_follow_logs(cx))),
+                    ),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .size_full()
+                    .font_family(buffer_font)
+                    .child(list(self.log_list.clone()).size_full()),
+            )
+    }
+
+    fn render_build_view(
+        &self,
+        build: &BuildImage,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let this = cx.view().clone();
+        let building = build.task.is_some();
+        let buffer_font = ThemeSettings::get_global(cx).buffer_font.family.clone();
+        let dockerfile_labels = build
+            .dockerfiles
+            .iter()
+            .map(Dockerfile::label)
+            .collect::<Vec<_>>();
+        let selected = build.selected;
+
+        v_flex()
+            .size_full()
+            .child(self.render_detail_header("Build Image".into(), cx))
+            .child(
+                v_flex()
+                    .px_2()
+                    .py_1()
+                    .gap_1()
+                    .border_b_1()
+                    .border_color(cx.theme().colors().border_variant)
+                    .child(
+                        popover_menu("dockerfile_picker")
+                            .trigger(
+                                Button::new("dockerfile", dockerfile_labels[selected].clone())
+                                    .icon(IconName::ChevronDown)
+                                    .icon_position(IconPosition::End)
+                                    .icon_size(IconSize::Small)
+                                    .label_size(LabelSize::Small)
+                                    .style(ButtonStyle::Subtle)
+                                    .disabled(building)
+                                    .tooltip(|cx| Tooltip::text("Dockerfile", cx)),
+                            )
+                            .menu(move |cx| {
+                                Some(ContextMenu::build(cx, |mut menu, cx| {
+                                    for (ix, label) in dockerfile_labels.iter().enumerate() {
+                                        menu = menu.toggleable_entry(
+                                            label.clone(),
+                                            ix == selected,
+                                            None,
+                                            cx.handler_for(&this, move |this, cx| {
+                                                this.select_dockerfile(ix, cx)
+                                            }),
+                                        );
+                                    }
+                                    menu
+                                }))
+                            }),
+                    )
+                    .child(
+                        div()
+                            .on_action(
+                                cx.listener(|this, _: &menu::Confirm, cx| this.build_image(cx)),
+                            )
+                            .child(build.tag_editor.clone()),
+                    )
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .on_action(cx.listener(|this, _: &menu::Confirm, cx| {
+                                        this.build_image(cx)
+                                    }))
+                                    .child(build.context_editor.clone()),
+                            )
+                            .map(|this| {
+                                if building {
+                                    this.child(
+                                        Button::new("cancel_build", "Cancel")
+                                            .label_size(LabelSize::Small)
+                                            .style(ButtonStyle::Filled)
+                                            .on_click(
+                                                cx.listener(|this, _, cx| this.cancel_build(cx)),
+                                            ),
+                                    )
+                                } else {
+                                    this.child(
+                                        Button::new("build_image", "Build")
+                                            .label_size(LabelSize::Small)
+                                            .style(ButtonStyle::Filled)
+                                            .on_click(
+                                                cx.listener(|this, _, cx| this.build_image(cx)),
+                                            ),
+                                    )
+                                }
+                            }),
+                    ),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .size_full()
+                    .font_family(buffer_font)
+                    .child(list(build.output_list.clone()).size_full()),
+            )
+    }
+
+    fn render_filter_input(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        h_flex()
+            .px_2()
+            .py_1()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().colors().border_variant)
+            .child(
+                Icon::new(IconName::MagnifyingGlass)
+                    .size(IconSize::Small)
+                    .color(Color::Muted),
+            )
+            .child(self.filter_editor.clone())
+    }
+
+    fn render_tab_bar(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let tab_button = |id: &'static str, label: &'static str, tab: PanelTab| {
+            Button::new(id, label)
+                .label_size(LabelSize::Small)
+                .style(ButtonStyle::Subtle)
+                .selected_style(ButtonStyle::Tinted(TintColor::Accent))
+                .selected(self.tab == tab)
+                .on_click(cx.listener(move |this, _, cx| this.set_tab(tab, cx)))
+        };
+
+        h_flex()
+            .px_2()
+            .py_1()
+            .gap_1()
+            .border_b_1()
+            .border_color(cx.theme().colors().border_variant)
+            .child(tab_button(
+                "containers_tab",
+                "Containers",
+                PanelTab::Containers,
+            ))
+            .child(tab_button("images_tab", "Images", PanelTab::Images))
+            .child(tab_button("volumes_tab", "Volumes", PanelTab::Volumes))
+    }
+
+    fn render_image(&self, ix: usize, image: &ImageEntry, cx: &mut ViewContext<Self>) -> Div {
+        let in_flight = self.image_tasks.contains_key(image.key());
+        h_flex()
+            .w_full()
+            .px_2()
+            .py_1()
+            .gap_2()
+            .justify_between()
+            .hover(|style| style.bg(cx.theme().colors().element_hover))
+            .child(
+                v_flex()
+                    .flex_1()
+                    .overflow_hidden()
+                    .child(Label::new(image.label()))
+                    .child(
+                        Label::new(format_bytes(image.size))
+                            .size(LabelSize::Small)
+                            .color(Color::Muted),
+                    ),
+            )
+            .child(self.render_created(format!("image-{ix}").into(), image.created))
+            .child(
+                h_flex()
+                    .flex_none()
+                    .gap_1()
+                    .child(
+                        IconButton::new(("run_image", ix), IconName::Play)
+                            .icon_size(IconSize::Small)
+                            .disabled(in_flight)
+                            .tooltip(|cx| Tooltip::text("Run", cx))
+                            .on_click(cx.listener({
+                                let image = image.clone();
+                                move |this, _, cx| this.run_image(image.clone(), cx)
+                            })),
+                    )
+                    .child(
+                        IconButton::new(("remove_image", ix), IconName::Trash)
+                            .icon_size(IconSize::Small)
+                            .disabled(in_flight)
+                            .tooltip(|cx| Tooltip::text("Remove", cx))
+                            .on_click(cx.listener({
+                                let image = image.clone();
+                                move |this, _, cx| this.remove_image(image.clone(), cx)
+                            })),
+                    ),
+            )
+    }
+
+    fn render_volume(&self, volume: &VolumeEntry, cx: &mut ViewContext<Self>) -> Div {
+        let users = self.volume_users(&volume.name);
+        let removing = self.volume_tasks.contains_key(&volume.name);
+        let remove_tooltip: SharedString = if users.is_empty() {
+            "Remove".into()
+        } else {
+            format!(
+                "In use by {}. Remove the containers first.",
+                users.join(", ")
+            )
+            .into()
+        };
+
+        h_flex()
+            .w_full()
+            .px_2()
+            .py_1()
+            .gap_2()
+            .justify_between()
+            .hover(|style| style.bg(cx.theme().colors().element_hover))
+            .child(
+                v_flex()
+                    .flex_1()
+                    .overflow_hidden()
+                    .child(Label::new(volume.name.clone()))
+                    .child(
+                        Label::new(format!("{} · {}", volume.driver, volume.mountpoint))
+                            .size(LabelSize::Small)
+                            .color(Color::Muted),
+                    )
+                    .when(!users.is_empty(), |this| {
+                        this.child(
+                            Label::new(format!("Used by {}", users.join(", ")))
+                                .size(LabelSize::XSmall)
+                                .color(Color::Muted),
+                        )
+                    }),
+            )
+            .child(
+                IconButton::new(
+                    SharedString::from(format!("remove-volume-{}", volume.name)),
+                    IconName::Trash,
+                )
+                .icon_size(IconSize::Small)
+                .disabled(removing || !users.is_empty())
+                .tooltip(move |cx| Tooltip::text(remove_tooltip.clone(), cx))
+                .on_click(cx.listener({
+                    let name = volume.name.clone();
+                    move |this, _, cx| this.remove_volume(name.clone(), cx)
+                })),
+            )
+    }
+
+    fn render_volumes(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .child(
+                h_flex()
+                    .px_2()
+                    .py_1()
+                    .justify_end()
+                    .border_b_1()
+                    .border_color(cx.theme().colors().border_variant)
+                    .child(
+                        Button::new("prune_volumes", "Prune Unused")
+                            .label_size(LabelSize::Small)
+                            .disabled(self.prune_volumes_task.is_some() || self.volumes.is_empty())
+                            .tooltip(|cx| Tooltip::text("Remove Volumes No Container Uses", cx))
+                            .on_click(cx.listener(|this, _, cx| this.prune_volumes(cx))),
+                    ),
+            )
+            .map(|this| {
+                if self.volumes.is_empty() {
+                    this.child(
+                        v_flex().p_4().child(
+                            div().flex().w_full().items_center().child(
+                                Label::new("No volumes.")
+                                    .color(Color::Muted)
+                                    .size(LabelSize::Small),
+                            ),
+                        ),
+                    )
+                } else {
+                    this.child(
+                        v_flex()
+                            .id("volumes")
+                            .size_full()
+                            .overflow_y_scroll()
+                            .children(
+                                self.volumes
+                                    .iter()
+                                    .map(|volume| self.render_volume(volume, cx)),
+                            ),
+                    )
+                }
+            })
+    }
+
+    fn render_pull_input(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let pulling = self.pull_task.is_some();
+        v_flex()
+            .px_2()
+            .py_1()
+            .gap_1()
+            .border_b_1()
+            .border_color(cx.theme().colors().border_variant)
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Icon::new(IconName::ArrowDown)
+                            .size(IconSize::Small)
+                            .color(Color::Muted),
+                    )
+                    .child(div().flex_1().child(self.pull_editor.clone()))
+                    .child(
+                        Button::new("start_build_image", "Build…")
+                            .label_size(LabelSize::Small)
+                            .style(ButtonStyle::Subtle)
+                            .disabled(self.build.is_some() || self.docker.is_none())
+                            .tooltip(|cx| Tooltip::text("Build Image from Dockerfile…", cx))
+                            .on_click(cx.listener(|this, _, cx| this.start_build_image(cx))),
+                    )
+                    .child(
+                        Button::new("pull_image", "Pull")
+                            .label_size(LabelSize::Small)
+                            .style(ButtonStyle::Filled)
+                            .disabled(pulling || self.docker.is_none())
+                            .when(pulling, |this| {
+                                this.icon(IconName::ArrowCircle)
+                                    .icon_position(IconPosition::Start)
+                                    .icon_size(IconSize::Small)
+                                    .icon_color(Color::Muted)
+                            })
+                            .on_click(cx.listener(|this, _, cx| this.pull_image(cx))),
+                    ),
+            )
+            .children(self.pull_progress.iter().map(|progress| {
+                Label::new(progress.to_string())
+                    .size(LabelSize::XSmall)
+                    .color(Color::Muted)
+            }))
+    }
+
+    fn render_images(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        if self.images.is_empty() {
+            return v_flex()
+                .p_4()
+                .child(
+                    div().flex().w_full().items_center().child(
+                        Label::new("No images.")
+                            .color(Color::Muted)
+                            .size(LabelSize::Small),
+                    ),
+                )
+                .into_any_element();
+        }
+
+        v_flex()
+            .id("images")
+            .size_full()
+            .overflow_y_scroll()
+            .children(
+                self.images
+                    .iter()
+                    .enumerate()
+                    .map(|(ix, image)| self.render_image(ix, image, cx)),
+            )
+            .into_any_element()
+    }
+
+    fn render_sort_control(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let this = cx.view().clone();
+        let sort = self.sort;
+        h_flex()
+            .child(
+                popover_menu("sort_menu")
+                    .trigger(
+                        Button::new("sort_key", sort.key.label())
+                            .label_size(LabelSize::Small)
+                            .style(ButtonStyle::Subtle)
+                            .tooltip(|cx| Tooltip::text("Sort By", cx)),
+                    )
+                    .anchor(gpui::AnchorCorner::TopRight)
+                    .menu(move |cx| {
+                        Some(ContextMenu::build(cx, |mut menu, cx| {
+                            for key in SortKey::ALL {
+                                menu = menu.toggleable_entry(
+                                    key.label(),
+                                    sort.key == key,
+                                    None,
+                                    cx.handler_for(&this, move |this, cx| {
+                                        this.set_sort_key(key, cx)
+                                    }),
+                                );
+                            }
+                            menu
+                        }))
+                    }),
+            )
+            .child(
+                IconButton::new(
+                    "sort_direction",
+                    if sort.descending {
+                        IconName::ArrowDown
+                    } else {
+                        IconName::ArrowUp
+                    },
+                )
+                .icon_size(IconSize::Small)
+                .tooltip(move |cx| {
+                    Tooltip::text(
+                        if sort.descending {
+                            "Descending"
+                        } else {
+                            "Ascending"
+                        },
+                        cx,
+                    )
+                })
+                .on_click(cx.listener(|this, _, cx| this.toggle_sort_direction(cx))),
+            )
+    }
+
+    fn render_select_mode_button(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        IconButton::new("select_mode", IconName::Check)
+            .icon_size(IconSize::Small)
+            .selected(self.selecting)
+            .tooltip(|cx| Tooltip::text("Select Containers", cx))
+            .on_click(cx.listener(|this, _, cx| this.toggle_select_mode(cx)))
+    }
+
+    fn render_copy_from_container_bar(
+        &self,
+        copy: &CopyFromContainer,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let name = self.container_name(&copy.container_id);
+        h_flex()
+            .px_2()
+            .py_1()
+            .gap_2()
+            .border_t_1()
+            .border_color(cx.theme().colors().border_variant)
+            .child(
+                Label::new(format!("Copy from {name}:"))
+                    .size(LabelSize::Small)
+                    .color(Color::Muted),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .on_action(cx.listener(|this, _: &menu::Confirm, cx| {
+                        this.confirm_copy_from_container(cx)
+                    }))
+                    .on_action(cx.listener(|this, _: &editor::actions::Cancel, cx| {
+                        this.cancel_copy_from_container(cx)
+                    }))
+                    .child(copy.path_editor.clone()),
+            )
+            .map(|this| match &copy.progress {
+                Some((bytes_read, size)) => {
+                    let bytes_read = bytes_read.load(atomic::Ordering::Relaxed);
+                    this.child(
+                        Label::new(format!(
+                            "{} / {}",
+                            format_bytes(bytes_read),
+                            format_bytes(*size)
+                        ))
+                        .size(LabelSize::Small)
+                        .color(Color::Muted),
+                    )
+                }
+                None => this.child(
+                    Button::new("confirm_copy_from_container", "Copy…")
+                        .label_size(LabelSize::Small)
+                        .style(ButtonStyle::Filled)
+                        .disabled(copy.task.is_some())
+                        .tooltip(|cx| Tooltip::text("Choose Where to Save the File", cx))
+                        .on_click(cx.listener(|this, _, cx| this.confirm_copy_from_container(cx))),
+                ),
+            })
+            .child(
+                IconButton::new("cancel_copy_from_container", IconName::Close)
+                    .icon_size(IconSize::Small)
+                    .tooltip(|cx| Tooltip::text("Cancel", cx))
+                    .on_click(cx.listener(|this, _, cx| this.cancel_copy_from_container(cx))),
+            )
+    }
+
+    fn render_commit_bar(
+        &self,
+        commit: &CommitContainer,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let name = self.container_name(&commit.container_id);
+        let committing = commit.task.is_some();
+        h_flex()
+            .px_2()
+            .py_1()
+            .gap_2()
+            .border_t_1()
+            .border_color(cx.theme().colors().border_variant)
+            .child(
+                Label::new(format!("Commit {name} to:"))
+                    .size(LabelSize::Small)
+                    .color(Color::Muted),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .on_action(cx.listener(|this, _: &menu::Confirm, cx| this.confirm_commit(cx)))
+                    .on_action(
+                        cx.listener(|this, _: &editor::actions::Cancel, cx| this.cancel_commit(cx)),
+                    )
+                    .child(commit.reference_editor.clone()),
+            )
+            .child(
+                Button::new("confirm_commit", "Commit")
+                    .label_size(LabelSize::Small)
+                    .style(ButtonStyle::Filled)
+                    .disabled(committing)
+                    .when(committing, |this| {
+                        this.icon(IconName::ArrowCircle)
+                            .icon_position(IconPosition::Start)
+                            .icon_size(IconSize::Small)
+                            .icon_color(Color::Muted)
+                    })
+                    .on_click(cx.listener(|this, _, cx| this.confirm_commit(cx))),
+            )
+            .child(
+                IconButton::new("cancel_commit", IconName::Close)
+                    .icon_size(IconSize::Small)
+                    .tooltip(|cx| Tooltip::text("Cancel", cx))
+                    .on_click(cx.listener(|this, _, cx| this.cancel_commit(cx))),
+            )
+    }
+
+    fn render_bulk_action_bar(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let count = self.selected.len();
+        let disabled = count == 0 || self.bulk_action_task.is_some();
+        let button = |id: &'static str, label: &'static str, action: ContainerAction| {
+            Button::new(id, label)
+                .label_size(LabelSize::Small)
+                .disabled(disabled)
+                .on_click(cx.listener(move |this, _, cx| this.run_bulk_action(action, cx)))
+        };
+
+        h_flex()
+            .px_2()
+            .py_1()
+            .gap_1()
+            .border_t_1()
+            .border_color(cx.theme().colors().border_variant)
+            .child(
+                div().flex_1().child(
+                    Label::new(format!("{count} selected"))
+                        .size(LabelSize::Small)
+                        .color(Color::Muted),
+                ),
+            )
+            .map(|this| {
+                if self.confirming_bulk_removal {
+                    this.child(
+                        Label::new(format!("Remove {count}?"))
+                            .size(LabelSize::Small)
+                            .color(Color::Warning),
+                    )
+                    .child(
+                        Button::new("confirm_bulk_removal", "Yes")
+                            .label_size(LabelSize::Small)
+                            .style(ButtonStyle::Filled)
+                            .disabled(disabled)
+                            .on_click(cx.listener(|this, _, cx| {
+                                this.run_bulk_action(ContainerAction::Remove { force: false }, cx)
+                            })),
+                    )
+                    .child(
+                        Button::new("cancel_bulk_removal", "No")
+                            .label_size(LabelSize::Small)
+                            .on_click(cx.listener(|this, _, cx| {
+                                this.confirming_bulk_removal = false;
+                                cx.notify();
+                            })),
+                    )
+                } else {
+                    this.child(button("bulk_start", "Start", ContainerAction::Start))
+                        .child(button("bulk_stop", "Stop", ContainerAction::Stop))
+                        .child(
+                            Button::new("bulk_remove", "Remove")
+                                .label_size(LabelSize::Small)
+                                .disabled(disabled)
+                                .on_click(cx.listener(|this, _, cx| {
+                                    this.confirming_bulk_removal = true;
+                                    cx.notify();
+                                })),
+                        )
+                }
+            })
+    }
+
+    fn render_overflow_menu(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let this = cx.view().clone();
+        let prune_label = if self.filter.trim().is_empty() {
+            "Prune Stopped Containers"
+        } else {
+            "Prune Stopped Matching Containers"
+        };
+        popover_menu("overflow_menu")
+            .trigger(
+                IconButton::new("overflow_menu_trigger", IconName::Ellipsis)
+                    .icon_size(IconSize::Small)
+                    .disabled(self.docker.is_none())
+                    .tooltip(|cx| Tooltip::text("More Actions", cx)),
+            )
+            .anchor(gpui::AnchorCorner::TopRight)
+            .menu(move |cx| {
+                Some(ContextMenu::build(cx, |menu, cx| {
+                    menu.entry(
+                        prune_label,
+                        None,
+                        cx.handler_for(&this, |this, cx| this.prune_stopped(cx)),
+                    )
+                }))
+            })
+    }
+
+    fn render_disk_usage_footer(
+        &self,
+        disk_usage: DiskUsage,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let reclaiming = self.reclaim_task.is_some();
+        h_flex()
+            .px_2()
+            .py_1()
+            .gap_2()
+            .justify_between()
+            .border_t_1()
+            .border_color(cx.theme().colors().border_variant)
+            .child(
+                Label::new(format!(
+                    "Images {} · Containers {} · Volumes {}",
+                    format_bytes(disk_usage.images),
+                    format_bytes(disk_usage.containers),
+                    format_bytes(disk_usage.volumes),
+                ))
+                .size(LabelSize::XSmall)
+                .color(Color::Muted),
+            )
+            .child(
+                Button::new(
+                    "reclaim_disk_space",
+                    format!("{} reclaimable", format_bytes(disk_usage.reclaimable)),
+                )
+                .label_size(LabelSize::XSmall)
+                .style(ButtonStyle::Subtle)
+                .disabled(disk_usage.reclaimable == 0 || reclaiming)
+                .when(reclaiming, |this| {
+                    this.icon(IconName::ArrowCircle)
+                        .icon_position(IconPosition::Start)
+                        .icon_size(IconSize::XSmall)
+                        .icon_color(Color::Muted)
+                })
+                .tooltip(|cx| Tooltip::text("Prune Unused Containers, Images and Volumes", cx))
+                .on_click(cx.listener(|this, _, cx| this.reclaim_disk_space(cx))),
+            )
+    }
+
+    fn render_refresh_button(&self, cx: &mut ViewContext<Self>) -> AnyElement {
+        if self.refreshing {
+            Icon::new(IconName::ArrowCircle)
+                .size(IconSize::Small)
+                .color(Color::Muted)
+                .with_animation(
+                    "refreshing",
+                    Animation::new(Duration::from_secs(2)).repeat(),
+                    |icon, delta| icon.transform(Transformation::rotate(percentage(delta))),
+                )
+                .into_any_element()
+        } else {
+            IconButton::new("refresh", IconName::ArrowCircle)
+                .icon_size(IconSize::Small)
+                .tooltip(|cx| Tooltip::text("Refresh", cx))
+                .on_click(cx.listener(|this, _, cx| this.refresh(cx)))
+                .into_any_element()
+        }
+    }
+}
+
+impl Render for DockerPanel {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .key_context(self.dispatch_context(cx))
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::select_next))
+            .on_action(cx.listener(Self::select_prev))
+            .on_action(cx.listener(Self::open_selected_logs))
+            .on_action(cx.listener(Self::stop_selected))
+            .on_action(cx.listener(Self::restart_selected))
+            .size_full()
+            .child(
+                h_flex()
+                    .justify_between()
+                    .px_2()
+                    .py_1()
+                    // Match the height of the tab bar so they line up.
+                    .h(rems(ui::Tab::CONTAINER_HEIGHT_IN_REMS))
+                    .border_b_1()
+                    .border_color(cx.theme().colors().border)
+                    .child(
+                        h_flex()
+                            .gap_1()
+                            .child(Label::new("Docker"))
+                            .child(self.render_connection_status())
+                            .children(self.render_context_picker(cx)),
+                    )
+                    .child(
+                        h_flex()
+                            .gap_1()
+                            .when(
+                                self.view == PanelView::List
+                                    && self.tab == PanelTab::Containers
+                                    && !self.containers.is_empty(),
+                                |this| {
+                                    this.child(self.render_sort_control(cx))
+                                        .child(self.render_select_mode_button(cx))
+                                },
+                            )
+                            .child(self.render_refresh_button(cx))
+                            .child(self.render_overflow_menu(cx)),
+                    ),
+            )
+            .when(self.view == PanelView::List, |this| {
+                this.child(self.render_tab_bar(cx))
+            })
+            .when(
+                self.view == PanelView::List && self.tab == PanelTab::Containers,
+                |this| {
+                    this.children(
+                        self.dev_container.as_ref().map(|dev_container| {
+                            self.render_dev_container_banner(dev_container, cx)
+                        }),
+                    )
+                    .when(!self.port_forwards.is_empty(), |this| {
+                        this.child(self.render_port_forwards(cx))
+                    })
+                },
+            )
+            .map(|this| {
+                if let PanelView::Logs { container_id } = &self.view {
+                    this.child(self.render_logs_view(container_id, cx))
+                } else if let PanelView::Inspect { container_id } = &self.view {
+                    this.child(self.render_inspect_view(container_id, cx))
+                } else if let (PanelView::Build, Some(build)) = (&self.view, &self.build) {
+                    this.child(self.render_build_view(build, cx))
+                } else if let (PanelView::RebuildDevContainer, Some(rebuild)) =
+                    (&self.view, &self.rebuild)
+                {
+                    this.child(self.render_rebuild_view(rebuild, cx))
+                } else if let (PanelView::DevContainerCommands, Some(commands)) =
+                    (&self.view, &self.dev_container_commands)
+                {
+                    this.child(self.render_dev_container_commands_view(commands, cx))
+                } else if matches!(self.connection, ConnectionState::Disconnected { .. }) {
+                    this.child(self.render_disconnected(cx))
+                } else if self.connection == ConnectionState::Connecting {
+                    this.child(self.render_connecting())
+                } else if self.tab == PanelTab::Images {
+                    this.child(self.render_pull_input(cx))
+                        .child(self.render_images(cx))
+                } else if self.tab == PanelTab::Volumes {
+                    this.child(self.render_volumes(cx))
+                } else if self.containers.is_empty() {
+                    this.child(
+                        v_flex().p_4().child(
+                            div().flex().w_full().items_center().child(
+                                Label::new("No containers.")
+                                    .color(Color::Muted)
+                                    .size(LabelSize::Small),
+                            ),
+                        ),
+                    )
+                } else {
+                    this.child(self.render_filter_input(cx))
+                        .map(|this| {
+                            if self.list_entries.is_empty() {
+                                this.child(
+                                    v_flex().p_4().child(
+                                        div().flex().w_full().items_center().child(
+                                            Label::new("No containers match")
+                                                .color(Color::Muted)
+                                                .size(LabelSize::Small),
+                                        ),
+                                    ),
+                                )
+                            } else {
+                                this.child(list(self.container_list.clone()).size_full())
+                            }
+                        })
+                        .children(
+                            self.copy_from_container
+                                .as_ref()
+                                .map(|copy| self.render_copy_from_container_bar(copy, cx)),
+                        )
+                        .children(
+                            self.committing
+                                .as_ref()
+                                .map(|commit| self.render_commit_bar(commit, cx)),
+                        )
+                        .when(self.selecting, |this| {
+                            this.child(self.render_bulk_action_bar(cx))
+                        })
+                }
+            })
+            .when(
+                self.view == PanelView::List && self.connection == ConnectionState::Connected,
+                |this| {
+                    this.children(
+                        self.disk_usage
+                            .map(|disk_usage| self.render_disk_usage_footer(disk_usage, cx)),
+                    )
+                },
+            )
+            .children(self.context_menu.as_ref().map(|(menu, position, _)| {
+                deferred(
+                    anchored()
+                        .position(*position)
+                        .anchor(gpui::AnchorCorner::TopLeft)
+                        .child(menu.clone()),
+                )
+                .with_priority(1)
+            }))
+    }
+}
+
+impl FocusableView for DockerPanel {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl EventEmitter<Event> for DockerPanel {}
+impl EventEmitter<PanelEvent> for DockerPanel {}
+
+impl Panel for DockerPanel {
+    fn persistent_name() -> &'static str {
+        "DockerPanel"
+    }
+
+    fn position(&self, _: &WindowContext) -> DockPosition {
+        self.position
+    }
+
+    fn position_is_valid(&self, position: DockPosition) -> bool {
+        matches!(position, DockPosition::Left | DockPosition::Right)
+    }
+
+    fn set_position(&mut self, position: DockPosition, cx: &mut ViewContext<Self>) {
+        self.position = position;
+        self.serialize(cx);
+        settings::update_settings_file::<DockerPanelSettings>(
+            self.fs.clone(),
+            cx,
+            move |settings| settings.dock = Some(position),
+        );
+    }
+
+    fn size(&self, cx: &WindowContext) -> Pixels {
+        self.width
+            .unwrap_or_else(|| DockerPanelSettings::get_global(cx).default_width)
+    }
+
+    fn set_size(&mut self, size: Option<Pixels>, cx: &mut ViewContext<Self>) {
+        self.width = size;
+        self.serialize(cx);
+        cx.notify();
+    }
+
+    fn set_active(&mut self, active: bool, cx: &mut ViewContext<Self>) {
+        let was_active = self.active;
+        self.active = active;
+
+        if self.active {
+            if let PanelView::Logs { container_id } = &self.view {
+                // Pick up the logs written while the panel was closed.
+                self.open_logs(container_id.clone(), cx);
+            }
+            self.fetch_containers(cx);
+            self.schedule_refresh(cx);
+            self.subscribe_to_events(cx);
+            self.poll_disk_usage(cx);
+        } else {
+            self.cancel_background_tasks();
+            for container in &mut self.containers {
+                container.stats = None;
+            }
+            if was_active {
+                cx.emit(Event::Dismissed);
+            }
+        }
+    }
+
+    fn icon(&self, cx: &WindowContext) -> Option<IconName> {
+        DockerPanelSettings::get_global(cx)
+            .button
+            .then_some(IconName::Server)
+    }
+
+    fn icon_tooltip(&self, _cx: &WindowContext) -> Option<&'static str> {
+        Some("Docker Panel")
+    }
+
+    fn icon_label(&self, _cx: &WindowContext) -> Option<String> {
+        let running = self
+            .containers
+            .iter()
+            .filter(|container| container.is_running())
+            .count();
+        if running == 0 {
+            None
+        } else {
+            Some(running.to_string())
+        }
+    }
+
+    fn toggle_action(&self) -> Box<dyn gpui::Action> {
+        Box::new(ToggleFocus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gpui::TestAppContext;
+    use project::FakeFs;
+    use std::{cell::Cell, rc::Rc};
+
+    #[test]
+    fn test_published_ports() {
+        let port = |ip: &str, private_port, public_port, protocol| Port {
+            ip: Some(ip.into()),
+            private_port,
+            public_port,
+            protocol,
+        };
+        let ports = published_ports(&[
+            port("0.0.0.0", 80, Some(8080), PortProtocol::Tcp),
+            port("::", 80, Some(8080), PortProtocol::Tcp),
+            port("0.0.0.0", 53, Some(5353), PortProtocol::Udp),
+            port("192.168.1.2", 443, Some(8443), PortProtocol::Tcp),
+            port("", 9000, None, PortProtocol::Tcp),
+        ]);
+
+        assert_eq!(
+            ports
+                .iter()
+                .map(|port| (port.label(), port.url()))
+                .collect::<Vec<_>>(),
+            [
+                ("5353→53/udp".to_string(), None),
+                (
+                    "8080→80".to_string(),
+                    Some("http://localhost:8080".to_string())
+                ),
+                ("8443→443".to_string(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_image_entries() {
+        let image = |id: &str, repo_tags: Option<Vec<&str>>| ImageSummary {
+            id: id.into(),
+            repo_tags: repo_tags.map(|tags| tags.into_iter().map(Into::into).collect()),
+            created: 0,
+            size: 0,
+        };
+        let entries = image_entries(vec![
+            image("sha256:0123456789abcdef", Some(vec!["<none>:<none>"])),
+            image(
+                "sha256:fedcba9876543210",
+                Some(vec!["rust:1.78", "rust:latest"]),
+            ),
+            image("sha256:1111111111111111", None),
+            image("sha256:2222222222222222", Some(vec!["postgres:16"])),
+        ]);
+
+        assert_eq!(
+            entries.iter().map(ImageEntry::label).collect::<Vec<_>>(),
+            [
+                "postgres:16",
+                "rust:1.78",
+                "rust:latest",
+                "<none> (0123456789ab)",
+                "<none> (111111111111)",
+            ]
+        );
+        assert_eq!(entries[1].key(), "rust:1.78");
+        assert_eq!(entries[3].key(), "sha256:0123456789abcdef");
+    }
+
+    #[test]
+    fn test_sort_by() {
+        let container = |name: &str, state, cpu_percent: Option<f32>| ContainerEntry {
+            id: name.into(),
+            name: name.into(),
+            image: "alpine".into(),
+            created: OffsetDateTime::UNIX_EPOCH,
+            state,
+            status: String::new(),
+            health: None,
+            health_check_output: None,
+            compose: None,
+            ports: Vec::new(),
+            mounts: Vec::new(),
+            dev_container_folder: None,
+            stats: cpu_percent.map(|cpu_percent| ContainerStats {
+                cpu_percent,
+                memory_usage: 0,
+                memory_limit: 0,
+            }),
+        };
+        let mut containers = vec![
+            container("web", ContainerState::Running, Some(12.5)),
+            container("db", ContainerState::Exited, None),
+            container("cache", ContainerState::Running, Some(40.)),
+            container("worker", ContainerState::Paused, None),
+        ];
+        let mut sorted = |sort: SortBy| {
+            containers.sort_by(|a, b| sort.compare(a, b));
+            containers
+                .iter()
+                .map(|container| container.name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let by_cpu = |descending| SortBy {
+            key: SortKey::Cpu,
+            descending,
+        };
+        assert_eq!(sorted(by_cpu(true)), ["cache", "web", "db", "worker"]);
+        assert_eq!(sorted(by_cpu(false)), ["web", "cache", "db", "worker"]);
+        assert_eq!(
+            sorted(SortBy {
+                key: SortKey::Status,
+                descending: false,
+            }),
+            ["cache", "web", "worker", "db"]
+        );
+        assert_eq!(
+            sorted(SortBy {
+                key: SortKey::Name,
+                descending: true,
+            }),
+            ["worker", "web", "db", "cache"]
+        );
+    }
+
+    #[test]
+    fn test_deserialize_unversioned_panel() {
+        let panel: SerializedDockerPanel =
+            serde_json::from_str(r#"{ "width": 320.0, "position": "left" }"#).unwrap();
+        assert_eq!(panel.version, 0);
+        assert_eq!(panel.width, Some(px(320.)));
+        assert_eq!(panel.position, Some(DockPosition::Left));
+        assert_eq!(panel.tab, None);
+        assert_eq!(panel.sort, None);
+        assert_eq!(panel.filter, "");
+    }
+
+    #[test]
+    fn test_is_dockerfile() {
+        assert!(is_dockerfile("Dockerfile"));
+        assert!(is_dockerfile("Dockerfile.dev"));
+        assert!(is_dockerfile("api.Dockerfile"));
+        assert!(is_dockerfile("api.dockerfile"));
+        assert!(!is_dockerfile("Dockerfiles"));
+        assert!(!is_dockerfile("docker-compose.yml"));
+    }
+
+    #[test]
+    fn test_is_valid_container_name() {
+        assert!(is_valid_container_name("web-1"));
+        assert!(is_valid_container_name("my_app.db"));
+        assert!(is_valid_container_name("0a"));
+        assert!(!is_valid_container_name(""));
+        assert!(!is_valid_container_name("a"));
+        assert!(!is_valid_container_name("-web"));
+        assert!(!is_valid_container_name("web app"));
+        assert!(!is_valid_container_name("web/1"));
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1024), "1 KiB");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(256 * 1024 * 1024), "256 MiB");
+        assert_eq!(format_bytes(1024 * 1024 * 1024), "1 GiB");
+    }
+
+    #[gpui::test]
+    async fn test_docker_host_changes_reconnect_once(cx: &mut TestAppContext) {
+        init_test(cx);
+        // Hosts the client doesn't support fail to connect without touching the network.
+        set_docker_host("ssh://initial", cx);
+
+        let fs = FakeFs::new(cx.executor());
+        let project = Project::test(fs, [], cx).await;
+        let workspace = cx.add_window(|cx| Workspace::test_new(project, cx));
+        let panel = workspace
+            .update(cx, |workspace, cx| DockerPanel::new(workspace, cx))
+            .unwrap();
+        cx.run_until_parked();
+
+        let connection_attempt = |cx: &mut TestAppContext| {
+            panel.update(cx, |panel, _| {
+                let ConnectionState::Disconnected { error } = &panel.connection else {
+                    panic!("expected the connection to fail");
+                };
+                (error.clone(), panel.last_connection_attempt)
+            })
+        };
+        let (error, initial_attempt) = connection_attempt(cx);
+        assert!(error.contains("ssh://initial"), "{error}");
+
+        for host in ["ssh://a", "ssh://ab", "ssh://abc"] {
+            set_docker_host(host, cx);
+            cx.executor().advance_clock(RECONNECT_DEBOUNCE / 2);
+            cx.run_until_parked();
+            assert_eq!(connection_attempt(cx).1, initial_attempt);
+        }
+
+        cx.executor().advance_clock(RECONNECT_DEBOUNCE);
+        cx.run_until_parked();
+        let (error, attempt) = connection_attempt(cx);
+        assert!(error.contains("ssh://abc"), "{error}");
+        assert_ne!(attempt, initial_attempt);
+
+        cx.executor().advance_clock(RECONNECT_DEBOUNCE * 2);
+        cx.run_until_parked();
+        assert_eq!(connection_attempt(cx).1, attempt);
+    }
+
+    #[gpui::test]
+    async fn test_dismissing_emits_event(cx: &mut TestAppContext) {
+        init_test(cx);
+        set_docker_host("ssh://unreachable", cx);
+
+        let fs = FakeFs::new(cx.executor());
+        let project = Project::test(fs, [], cx).await;
+        let workspace = cx.add_window(|cx| Workspace::test_new(project, cx));
+        let panel = workspace
+            .update(cx, |workspace, cx| DockerPanel::new(workspace, cx))
+            .unwrap();
+        cx.run_until_parked();
+
+        let dismissals = Rc::new(Cell::new(0));
+        let _subscription = cx.update(|cx| {
+            let dismissals = dismissals.clone();
+            cx.subscribe(&panel, move |_, event: &Event, _| {
+                if matches!(event, Event::Dismissed) {
+                    dismissals.set(dismissals.get() + 1);
+                }
+            })
+        });
+
+        // Deactivating a panel that was never opened doesn't dismiss it.
+        workspace
+            .update(cx, |_, cx| {
+                panel.update(cx, |panel, cx| panel.set_active(false, cx))
+            })
+            .unwrap();
+        assert_eq!(dismissals.get(), 0);
+
+        workspace
+            .update(cx, |_, cx| {
+                panel.update(cx, |panel, cx| {
+                    panel.set_active(true, cx);
+                    // There's no daemon to poll, so stand in for a running container's poller.
+                    panel.containers = vec![ContainerEntry {
+                        id: "web".into(),
+                        name: "web".into(),
+                        image: "alpine".into(),
+                        created: OffsetDateTime::UNIX_EPOCH,
+                        state: ContainerState::Running,
+                        status: String::new(),
+                        health: None,
+                        health_check_output: None,
+                        compose: None,
+                        ports: Vec::new(),
+                        mounts: Vec::new(),
+                        dev_container_folder: None,
+                        stats: Some(ContainerStats {
+                            cpu_percent: 12.5,
+                            memory_usage: 0,
+                            memory_limit: 0,
+                        }),
+                    }];
+                    panel
+                        .stats_tasks
+                        .insert("web".into(), cx.spawn(|_, _| futures::future::pending()));
+                    panel.set_active(false, cx);
+                })
+            })
+            .unwrap();
+        cx.run_until_parked();
+        assert_eq!(dismissals.get(), 1);
+        panel.update(cx, |panel, _| {
+            assert!(panel.stats_tasks.is_empty());
+            assert!(panel.containers[0].stats.is_none());
+        });
+    }
+
+    #[gpui::test]
+    async fn test_log_lines_are_capped(cx: &mut TestAppContext) {
+        init_test(cx);
+        set_docker_host("ssh://unreachable", cx);
+
+        let fs = FakeFs::new(cx.executor());
+        let project = Project::test(fs, [], cx).await;
+        let workspace = cx.add_window(|cx| Workspace::test_new(project, cx));
+        let panel = workspace
+            .update(cx, |workspace, cx| DockerPanel::new(workspace, cx))
+            .unwrap();
+
+        workspace
+            .update(cx, |_, cx| {
+                panel.update(cx, |panel, cx| {
+                    let lines = |range: std::ops::Range<usize>| {
+                        range.map(|ix| format!("line {ix}")).collect::<Vec<_>>()
+                    };
+                    panel.append_log_lines(lines(0..MAX_LOG_LINES - 1), cx);
+                    assert_eq!(panel.log_lines.len(), MAX_LOG_LINES - 1);
+                    panel.append_log_lines(lines(MAX_LOG_LINES - 1..MAX_LOG_LINES + 5), cx);
+                })
+            })
+            .unwrap();
+
+        panel.update(cx, |panel, _| {
+            assert_eq!(panel.log_lines.len(), MAX_LOG_LINES);
+            assert_eq!(panel.log_list.item_count(), MAX_LOG_LINES);
+            assert_eq!(panel.log_lines.front().unwrap().to_string(), "line 5");
+            assert_eq!(
+                panel.log_lines.back().unwrap().to_string(),
+                format!("line {}", MAX_LOG_LINES + 4)
+            );
+        });
+    }
+
+    fn set_docker_host(docker_host: &str, cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            cx.update_global::<SettingsStore, _>(|store, cx| {
+                store.update_user_settings::<DockerPanelSettings>(cx, |settings| {
+                    settings.docker_host = Some(docker_host.into());
+                });
+            });
+        });
+    }
+
+    fn init_test(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let settings_store = SettingsStore::test(cx);
+            cx.set_global(settings_store);
+            theme::init(theme::LoadThemes::JustBase, cx);
+            language::init(cx);
+            editor::init_settings(cx);
+            workspace::init_settings(cx);
+            Project::init_settings(cx);
+            crate::init(cx);
+        });
+    }
+}
@@ -0,0 +1,64 @@
+use gpui::Pixels;
+use schemars::JsonSchema;
+use serde_derive::{Deserialize, Serialize};
+use settings::{Settings, SettingsSources};
+use workspace::dock::DockPosition;
+
+#[derive(Deserialize, Debug)]
+pub struct DockerPanelSettings {
+    pub button: bool,
+    pub dock: DockPosition,
+    pub default_width: Pixels,
+    pub refresh_interval: u64,
+    pub docker_host: Option<String>,
+    pub default_shell: String,
+    pub max_copied_log_lines: usize,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize, JsonSchema, Debug)]
+pub struct PanelSettingsContent {
+    /// Whether to show the panel button in the status bar.
+    ///
+    /// Default: true
+    pub button: Option<bool>,
+    /// Where to dock the panel.
+    ///
+    /// Default: right
+    pub dock: Option<DockPosition>,
+    /// Default width of the panel in pixels.
+    ///
+    /// Default: 360
+    pub default_width: Option<f32>,
+    /// How often to refresh the container list while the panel is open, in seconds.
+    /// Set to 0 to disable automatic refreshing.
+    ///
+    /// Default: 5
+    pub refresh_interval: Option<u64>,
+    /// The Docker daemon to connect to, e.g. `unix:///var/run/docker.sock` or
+    /// `tcp://localhost:2375`. When unset, `DOCKER_HOST` is used if present.
+    ///
+    /// Default: null
+    pub docker_host: Option<String>,
+    /// The shell to run when opening a shell in a container.
+    ///
+    /// Default: /bin/sh
+    pub default_shell: Option<String>,
+    /// The maximum number of log lines to copy to the clipboard, counting back
+    /// from the latest one.
+    ///
+    /// Default: 10000
+    pub max_copied_log_lines: Option<usize>,
+}
+
+impl Settings for DockerPanelSettings {
+    const KEY: Option<&'static str> = Some("docker_panel");
+
+    type FileContent = PanelSettingsContent;
+
+    fn load(
+        sources: SettingsSources<Self::FileContent>,
+        _: &mut gpui::AppContext,
+    ) -> anyhow::Result<Self> {
+        sources.json_merge()
+    }
+}
@@ -0,0 +1,74 @@
+use anyhow::{anyhow, Result};
+use futures::{future, io, stream::FuturesUnordered, FutureExt, StreamExt};
+use smol::{
+    net::{TcpListener, TcpStream},
+    Timer,
+};
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    time::Duration,
+};
+
+/// How long to wait for the container to answer before deciding it can't be reached.
+const REACHABILITY_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Checks that the host can connect to `target`, which isn't the case for a
+/// container's network address when the daemon runs in a VM, as with Docker
+/// Desktop on macOS and Windows. A refused connection still counts, since the
+/// container may not be listening yet.
+pub async fn check_reachable(target: SocketAddr) -> Result<()> {
+    let connect = async { Some(TcpStream::connect(target).await) };
+    let timeout = async {
+        Timer::after(REACHABILITY_TIMEOUT).await;
+        None
+    };
+    match smol::future::or(connect, timeout).await {
+        Some(Ok(_)) => Ok(()),
+        Some(Err(error)) if error.kind() == std::io::ErrorKind::ConnectionRefused => Ok(()),
+        Some(Err(error)) => Err(anyhow!(
+            "{} isn't reachable from this host: {error}",
+            target.ip()
+        )),
+        None => Err(anyhow!("{} isn't reachable from this host", target.ip())),
+    }
+}
+
+/// Listens on `localhost`, at `port` if it's free or else at a port picked by
+/// the OS, for connections to forward to a container.
+pub async fn listen(port: u16) -> Result<TcpListener> {
+    match TcpListener::bind((Ipv4Addr::LOCALHOST, port)).await {
+        Ok(listener) => Ok(listener),
+        Err(_) => Ok(TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?),
+    }
+}
+
+/// Relays each connection accepted by `listener` to `target`, until dropped,
+/// which closes the connections being relayed.
+pub async fn forward_connections(listener: TcpListener, target: SocketAddr) -> Result<()> {
+    let mut connections = FuturesUnordered::new();
+    loop {
+        futures::select_biased! {
+            accepted = listener.accept().fuse() => {
+                let (client, _) = accepted?;
+                connections.push(relay(client, target));
+            }
+            _ = connections.select_next_some() => {}
+        }
+    }
+}
+
+async fn relay(client: TcpStream, target: SocketAddr) {
+    let result = async {
+        let server = TcpStream::connect(target).await?;
+        future::try_join(
+            io::copy(client.clone(), &mut server.clone()),
+            io::copy(server, &mut client.clone()),
+        )
+        .await?;
+        anyhow::Ok(())
+    }
+    .await;
+    if let Err(error) = result {
+        log::warn!("failed to forward a connection to {target}: {error:?}");
+    }
+}
@@ -156,6 +156,7 @@ pub enum IconName {
     Option,
     PageDown,
     PageUp,
+    Pause,
     Pencil,
     Person,
     Play,
@@ -286,6 +287,7 @@ impl IconName {
             IconName::Option => "icons/option.svg",
             IconName::PageDown => "icons/page_down.svg",
             IconName::PageUp => "icons/page_up.svg",
+            IconName::Pause => "icons/pause.svg",
             IconName::Pencil => "icons/pencil.svg",
             IconName::Person => "icons/person.svg",
             IconName::Play => "icons/play.svg",